@@ -0,0 +1,321 @@
+use gloo::dialogs::{alert, confirm};
+use web_sys::HtmlInputElement;
+
+use crate::prelude::*;
+
+fn bind_input(state: &UseStateHandle<String>) -> Callback<InputEvent> {
+    let state = state.clone();
+    Callback::from(move |e: InputEvent| {
+        state.set(e.target_unchecked_into::<HtmlInputElement>().value());
+    })
+}
+
+/// Turns a backend response into the message shown under a form. Error bodies are
+/// not shown as they are, since they are neither localized nor meant for users.
+async fn response_message(
+    result: Result<gloo::net::http::Response, gloo::net::Error>,
+    success: &str,
+) -> Result<String, String> {
+    match result {
+        Ok(r) if r.ok() => Ok(success.to_string()),
+        Ok(r) => Err(failure_message(r.status())),
+        Err(GlooError(msg)) => Err(msg),
+        Err(e) => {
+            log!(format!("{:?}", e));
+            Err(t!("account.network_error"))
+        }
+    }
+}
+
+fn failure_message(status: u16) -> String {
+    match status {
+        400 | 422 => t!("account.request_rejected"),
+        401 | 403 => t!("account.not_authorized"),
+        409 => t!("auth.username_taken"),
+        s => t!("account.request_failed", status = s),
+    }
+}
+
+fn status_html(status: &Option<Result<String, String>>) -> Html {
+    match status {
+        None => html! {},
+        Some(Ok(msg)) => html! {<span class="success-plaintext">{msg}</span>},
+        Some(Err(msg)) => html! {<span class="warning-plaintext">{msg}</span>},
+    }
+}
+
+#[function_component(AccountSettings)]
+pub fn account_settings() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let nav = use_navigator().unwrap();
+    use_title(format!("{} | ANiNFO", t!("account.page_title")));
+
+    let current_password = use_state(|| "".to_string());
+    let new_password = use_state(|| "".to_string());
+    let new_password_verify = use_state(|| "".to_string());
+    let password_status = use_state(|| None::<Result<String, String>>);
+
+    let new_username = use_state(|| "".to_string());
+    let username_status = use_state(|| None::<Result<String, String>>);
+
+    let delete_password = use_state(|| "".to_string());
+    let delete_status = use_state(|| None::<Result<String, String>>);
+
+    let export_status = use_state(|| None::<Result<String, String>>);
+
+    let privacy = use_state(|| None::<ProfilePrivacy>);
+    let privacy_status = use_state(|| None::<Result<String, String>>);
+
+    {
+        let cx = cx.clone();
+        let privacy = privacy.clone();
+        use_effect_with((*cx).username.clone(), move |username| {
+            if let (Some(username), true) = (username.clone(), cx.jwt.is_some()) {
+                wasm_bindgen_futures::spawn_local(async move {
+                    match fetch_profile(&cx, &username).await {
+                        Ok(ProfileLookup::Visible(p)) => privacy.set(Some(p.privacy)),
+                        Ok(ProfileLookup::Restricted(p)) => privacy.set(Some(p.privacy)),
+                        Ok(ProfileLookup::NotFound) => {}
+                        Err(e) => log!(format!("{:?}", e)),
+                    }
+                });
+            }
+        });
+    }
+
+    if (*cx).jwt.is_none() {
+        return html! {
+            <div class="search-result-wrapper">
+            <div class="written-content">
+                <div class="content-header">
+                    <span><h2 class="content-ttl vc-text">{t!("account.page_title")}</h2></span>
+                </div>
+                <p style="text-align: center !important;">{t!("account.login_required")}</p>
+            </div>
+            </div>
+        };
+    }
+
+    let change_password = {
+        let cx = cx.clone();
+        let (current_password, new_password, new_password_verify) = (
+            current_password.clone(),
+            new_password.clone(),
+            new_password_verify.clone(),
+        );
+        let password_status = password_status.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if !verify_password(&new_password, &new_password_verify) {
+                password_status.set(Some(Err(t!("account.password_invalid"))));
+                return;
+            }
+
+            let cx = cx.clone();
+            let password_status = password_status.clone();
+            let body = ChangePasswordRequest {
+                current_password: (*current_password).clone(),
+                new_password: (*new_password).clone(),
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = AuthClient::new(&cx)
+                    .post_json(&format!("{}/api/v1/users/change_password", BASE_URL), &body)
+                    .await;
+                password_status.set(Some(
+                    response_message(result, &t!("account.password_changed")).await,
+                ));
+            });
+        })
+    };
+
+    let change_username = {
+        let cx = cx.clone();
+        let new_username = new_username.clone();
+        let username_status = username_status.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let username = (*new_username).trim().to_string();
+            if username.is_empty() {
+                username_status.set(Some(Err(t!("account.username_required"))));
+                return;
+            }
+            if let Err(msg) = validate_username(&username) {
+                username_status.set(Some(Err(msg)));
+                return;
+            }
+
+            let cx = cx.clone();
+            let username_status = username_status.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = AuthClient::new(&cx)
+                    .post_json(
+                        &format!("{}/api/v1/users/change_username", BASE_URL),
+                        &ChangeUsernameRequest {
+                            username: username.clone(),
+                        },
+                    )
+                    .await;
+                let msg = response_message(result, &t!("account.username_changed")).await;
+                if msg.is_ok() {
+                    cx.dispatch((*cx).update_username_into(Some(username)).into());
+                }
+                username_status.set(Some(msg));
+            });
+        })
+    };
+
+    let delete_account = {
+        let cx = cx.clone();
+        let nav = nav.clone();
+        let delete_password = delete_password.clone();
+        let delete_status = delete_status.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if !confirm(&t!("account.delete_confirm")) {
+                return;
+            }
+
+            let cx = cx.clone();
+            let nav = nav.clone();
+            let delete_status = delete_status.clone();
+            let body = DeleteAccountRequest {
+                password: (*delete_password).clone(),
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = AuthClient::new(&cx)
+                    .post_json(&format!("{}/api/v1/users/delete", BASE_URL), &body)
+                    .await;
+                match response_message(result, "").await {
+                    Ok(_) => {
+                        // Same as logging out, so the refresh cookie is revoked too
+                        end_session(&cx).await;
+                        alert(&t!("account.deleted"));
+                        nav.push(&Route::HomeNew);
+                    }
+                    Err(msg) => delete_status.set(Some(Err(msg))),
+                }
+            });
+        })
+    };
+
+    let change_privacy = |next: ProfilePrivacy| {
+        let cx = cx.clone();
+        let privacy = privacy.clone();
+        let privacy_status = privacy_status.clone();
+        Callback::from(move |_: MouseEvent| {
+            let cx = cx.clone();
+            let privacy = privacy.clone();
+            let privacy_status = privacy_status.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match set_profile_privacy(&cx, next).await {
+                    Ok(_) => {
+                        privacy.set(Some(next));
+                        privacy_status.set(Some(Ok(t!("profile.privacy_saved"))));
+                    }
+                    Err(e) => privacy_status.set(Some(Err(e.to_string()))),
+                }
+            });
+        })
+    };
+
+    let view_profile = {
+        let nav = nav.clone();
+        let username = (*cx).username.clone().unwrap_or_default();
+        Callback::from(move |_: MouseEvent| {
+            nav.push(&Route::UserProfile {
+                username: username.clone(),
+            });
+        })
+    };
+
+    let export_data = {
+        let cx = cx.clone();
+        let export_status = export_status.clone();
+        Callback::from(move |_: MouseEvent| {
+            let cx = cx.clone();
+            let export_status = export_status.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let account = AuthClient::new(&cx)
+                    .get_json::<UserDataExport>(&format!("{}/api/v1/users/export", BASE_URL))
+                    .await;
+                let status = match account {
+                    Ok(account) => {
+                        let bundle = DataExportBundle {
+                            exported_at: Utc::now().to_rfc3339(),
+                            settings: load_local_settings()
+                                .unwrap_or_else(|| UserSettings::from_ctx(&cx)),
+                            account,
+                        };
+                        download_json(&format!("aninfo-{}.json", bundle.account.username), &bundle)
+                            .map(|_| t!("account.downloaded"))
+                    }
+                    Err(e) => Err(e.to_string()),
+                };
+                export_status.set(Some(status));
+            });
+        })
+    };
+
+    html! {
+        <div class="search-result-wrapper">
+        <div class={format!("written-content account-wrapper {}", theme)}>
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("account.page_title")}</h2></span>
+            </div>
+
+            <h3>{t!("account.change_password")}</h3>
+            <form class="account-form" onsubmit={change_password}>
+                <input class={format!("nb-query {}", theme)} type="password" oninput={bind_input(&current_password)} placeholder={t!("account.current_password")}/>
+                <input class={format!("nb-query {}", theme)} type="password" oninput={bind_input(&new_password)} placeholder={t!("account.new_password")}/>
+                <input class={format!("nb-query {}", theme)} type="password" oninput={bind_input(&new_password_verify)} placeholder={t!("account.confirm_new_password")}/>
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} type="submit">{t!("account.change_password")}</button>
+                {status_html(&password_status)}
+            </form>
+
+            <h3>{t!("account.change_username")}</h3>
+            <form class="account-form" onsubmit={change_username}>
+                <input class={format!("nb-query {}", theme)} type="text" oninput={bind_input(&new_username)} placeholder={(*cx).username.clone().unwrap_or_default()}/>
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} type="submit">{t!("account.change_username")}</button>
+                {status_html(&username_status)}
+            </form>
+
+            <h3>{t!("profile.privacy_heading")}</h3>
+            <p>
+                {t!("profile.privacy_description")}
+                <a class="a-btn u_onhover cursor-pointer" onclick={view_profile}>{t!("profile.view_own")}</a>
+            </p>
+            <div class="account-form">
+                <div class="privacy-options">
+                {
+                    ProfilePrivacy::ALL.iter().map(|p| {
+                        let selected = *privacy == Some(*p);
+                        html! {
+                            <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {} {}", theme, if selected { "selected" } else { "" })} onclick={change_privacy(*p)}>
+                                {t!(p.label_key())}
+                            </button>
+                        }
+                    }).collect::<Html>()
+                }
+                </div>
+                {status_html(&privacy_status)}
+            </div>
+
+            <h3>{t!("account.download_heading")}</h3>
+            <p>{t!("account.download_description")}</p>
+            <div class="account-form">
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} onclick={export_data}>{t!("account.download")}</button>
+                {status_html(&export_status)}
+            </div>
+
+            <h3>{t!("account.delete_heading")}</h3>
+            <p>{t!("account.delete_description")}</p>
+            <form class="account-form" onsubmit={delete_account}>
+                <input class={format!("nb-query {}", theme)} type="password" oninput={bind_input(&delete_password)} placeholder={t!("auth.password_placeholder")}/>
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 danger-btn {}", theme)} type="submit">{t!("account.delete_heading")}</button>
+                {status_html(&delete_status)}
+            </form>
+        </div>
+        </div>
+    }
+}
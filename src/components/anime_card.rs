@@ -26,6 +26,13 @@ pub fn anime_card(props: &AnimeCardProp) -> Html {
     };
 
     let window = use_window_size();
+    let (compare_list, compare_dispatch) = use_store::<CompareList>();
+    let is_compared = compare_list.contains(mal_id);
+
+    let toggle_compare = Callback::from(move |e: MouseEvent| {
+        e.stop_propagation();
+        compare_dispatch.reduce_mut(|l| l.toggle(mal_id));
+    });
 
 
     html! {
@@ -35,9 +42,9 @@ pub fn anime_card(props: &AnimeCardProp) -> Html {
                 <h4 class="title">
                     <a class="cursor-pointer" onclick={go_to_detail}>{
                         if window.0 <= 750f64 {
-                            handle_long_title_compact(&handle_title_language(&cx, &ao), &cx)
+                            handle_long_title_compact(&display_title(&cx, &ao), &cx)
                         } else {
-                            handle_long_title(&handle_title_language(&cx, &ao), &cx)
+                            handle_long_title(&display_title(&cx, &ao), &cx)
                         }
                     }</a>
                 </h4>
@@ -66,13 +73,16 @@ pub fn anime_card(props: &AnimeCardProp) -> Html {
                             move |e: MouseEvent| {
                                 e.stop_propagation();
                                 nav.push(&Route::AnimeResultStd {
-                                    content_title: format!("Top {} Anime", genre_name),
+                                    content_title: t!("details.top_genre", genre = genre_name),
                                     page: 1,
                                     url: StdResultType::Genre(genre_obj.mal_id)})
                             }
                         }>{genre_obj.name.clone()}</a></span>)
                     }).collect::<Html>()}
                 </article>
+                <a class={format!("compare-toggle cursor-pointer u_onhover {}", if is_compared { "compare-toggle-active" } else { "" })} onclick={toggle_compare}>
+                    {if is_compared { t!("compare.added") } else { t!("compare.add") }}
+                </a>
             </article>
         </div>
     }
@@ -10,7 +10,7 @@ use crate::prelude::*;
 fn handle_year(year: Option<u32>) -> Html {
     match year {
         Some(n) => {
-            html! {<div class="meta-header">{"Year"}<span class="meta-data">
+            html! {<div class="meta-header">{t!("details.year")}<span class="meta-data">
                 <span class="ad-year">{n}</span>
                 </span></div>
             }
@@ -27,7 +27,7 @@ fn handle_rating_html(rating: Option<String>, scored_by: Option<u64>) -> Html {
                 html! {}
             } else {
                 html! {
-                    <div class="meta-header">{"Rating"}<span class="meta-data"><span class="ad-rating">{r}<span class="fw-150">{crate::utils::data_handling::handle_rating(scored_by)}</span></span></span></div>
+                    <div class="meta-header">{t!("details.rating")}<span class="meta-data"><span class="ad-rating">{r}<span class="fw-150">{crate::utils::data_handling::handle_rating(scored_by)}</span></span></span></div>
                 }
             }
         }
@@ -40,7 +40,7 @@ fn handle_studios(studios: Vec<MALObj>) -> Html {
         return html! {};
     } else {
         html! {
-            <div class="meta-header">{"Studios"}<span class="meta-data"><span class="ad-studios">{
+            <div class="meta-header">{t!("details.studios")}<span class="meta-data"><span class="ad-studios">{
                 studios.into_iter().enumerate().map(|(m, studio)| {
                     if m+1 == n {
                         html! (<>{studio.name}</>)
@@ -58,12 +58,12 @@ fn handle_synopsis(synopsis: Option<String>, theme: String) -> Html {
     match synopsis {
         None => html! {
         <div class="ad-synopsis">
-        <div class="ad-section-header"><img id="synopsis-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/bookmark.svg"/><h2 id="ad-section-header" class="content-ttl">{"Synopsis"}</h2></div>
-        {"Synopsis is unavailable."}
+        <div class="ad-section-header"><img id="synopsis-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/bookmark.svg"/><h2 id="ad-section-header" class="content-ttl">{t!("details.synopsis")}</h2></div>
+        {t!("details.synopsis_unavailable")}
         </div>},
         Some(s) => html! {
             <div class="ad-synopsis">
-            <div class="ad-section-header"><img id="synopsis-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/bookmark.svg"/><h2 id="ad-section-header" class="content-ttl">{"Synopsis"}</h2></div>
+            <div class="ad-section-header"><img id="synopsis-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/bookmark.svg"/><h2 id="ad-section-header" class="content-ttl">{t!("details.synopsis")}</h2></div>
             <p>{s}</p>
             </div>
         },
@@ -77,7 +77,7 @@ fn handle_anime_theme(themes: Vec<MALObj>) -> Html {
         return html! {};
     } else {
         html! {
-            <div class="meta-header">{"Theme"}<span class="meta-data"><span class="ad-themes">{
+            <div class="meta-header">{t!("details.theme")}<span class="meta-data"><span class="ad-themes">{
                 themes.into_iter().map(|theme| {
                     if m == n {
                         html! (<>{theme.name}</>)
@@ -100,10 +100,10 @@ pub fn handle_date(date: Option<String>) -> Html {
             match date {
                 Err(_) => html! {">> chrono: error parsing date."},
                 Ok(dt) => {
-                    let date_displayed = format!("{}", dt.format("%A, %e %B %Y"));
+                    let date_displayed = format_date(&dt.date_naive());
                     html! {<>
-                        <div class="meta-header">{"Status "}<div class="meta-data">
-                            {"Airing on "}<u>{&date_displayed}</u>
+                        <div class="meta-header">{t!("details.status")}<div class="meta-data">
+                            {t!("details.airing_on")}<u>{&date_displayed}</u>
                         </div>
                         </div>
                     </>}
@@ -118,7 +118,7 @@ fn handle_eps(eps: usize) -> Html {
         0 => html! {},
         _ => {
             html! {
-                <div class="meta-header">{"Episodes"}<span class="meta-data">{eps}</span></div>
+                <div class="meta-header">{t!("details.episodes")}<span class="meta-data">{eps}</span></div>
             }
         }
     }
@@ -135,6 +135,7 @@ pub fn content(props: &Props) -> HtmlResult {
     let theme = handle_theme(&cx);
     let mal_id = props.mal_id;
     let nav = use_navigator().unwrap();
+    let (episodes, _) = use_store::<EpisodeStore>();
 
     use_effect(|| {
         wasm_bindgen_futures::spawn_local(async move {
@@ -171,82 +172,59 @@ pub fn content(props: &Props) -> HtmlResult {
             // log!("Requesting anime details...");
             let link_ao = format!("https://api.jikan.moe/v4/anime/{}/full", mal_id);
             let link_co = format!("https://api.jikan.moe/v4/anime/{}/characters", mal_id);
-            let link_eps = format!("https://api.jikan.moe/v4/anime/{}/episodes", mal_id);
             let link_rec = format!("https://api.jikan.moe/v4/anime/{}/recommendations", mal_id);
 
             let ao_req = force_req::<AnimeObjFullAsQueryResult>(&link_ao);
             let char_req = force_req::<CharWrapper>(&link_co);
-            let eps_req = force_req::<AnimeEpisodeWrapper>(&link_eps);
             let rec_req = force_req::<AnimeRecResult>(&link_rec);
 
-            let (ao_result, char_result, eps_result, rec_result) =
-                join!(ao_req, char_req, eps_req, rec_req);
-            log!(format!("{:#?}", ao_result.as_ref().unwrap().data.aired));
-            log!(format!("{:#?}", ao_result.as_ref().unwrap().data.status));
-            let last_page = eps_result.as_ref().unwrap().pagination.last_visible_page;
-            let eps_info = *ao_result
-                .as_ref()
-                .unwrap()
-                .data
-                .episodes
-                .as_ref()
-                .unwrap_or(&0);
-
-            let mut total_episodes = 0;
-
-            if eps_info == 0 {
-                if last_page > 1 {
-                    let link = format!("{}?page={}", &link_eps, last_page);
-                    let n_last_page = force_req::<AnimeEpisodeWrapper>(&link)
-                        .await
-                        .unwrap()
-                        .data
-                        .len();
-                    total_episodes = ((last_page - 1) * 100 + n_last_page as u32) as usize;
-                } else {
-                    total_episodes = eps_result.as_ref().unwrap().data.len();
-                }
-            } else {
-                if let Some(t) = &ao_result.as_ref().unwrap().data.r#type.as_ref() {
-                    if t.as_str() == "Movie" {
-                        total_episodes = 0;
-                    } else {
-                        total_episodes = eps_info as usize;
-                    }
+            let (ao_result, char_result, rec_result) = join!(ao_req, char_req, rec_req);
+
+            // The episode list itself is left to `EpisodeCards`, which fetches its
+            // pages as they are shown. Without a listed count, e.g. while airing, the
+            // total comes from that list's pagination.
+            let listed_episodes = ao_result.as_ref().ok().and_then(|ao| {
+                if ao.data.r#type.as_deref() == Some("Movie") {
+                    Some(0)
                 } else {
-                    total_episodes = eps_info as usize;
+                    ao.data.episodes.filter(|n| *n > 0).map(|n| n as usize)
                 }
-            }
+            });
 
-            (
-                ao_result,
-                char_result,
-                eps_result,
-                rec_result,
-                Ok::<usize, &'static str>(total_episodes),
-            )
+            (ao_result, char_result, rec_result, listed_episodes)
         })?
     };
 
-    let html_result = match &(*result) {
-        (Ok(ao), Ok(co), Ok(eo), Ok(ro), Ok(n)) => {
-            log!(
-                "Setting title to:",
-                handle_title_language(&cx, &(ao.data.clone().into()))
-            );
-            web_sys::window().unwrap().document().unwrap().set_title(
-                format!(
-                    "{} | ANiNFO",
-                    handle_title_language(&cx, &(ao.data.clone().into()))
-                )
-                .as_str(),
-            );
+    // Only when the anime or its shown title changes, not on every re-render
+    {
+        let viewed = result.0.as_ref().ok().map(|ao| {
+            (display_title(&cx, &(ao.data.clone().into())), ao.data.images.webp.image_url.clone())
+        });
+        use_effect_with((mal_id, viewed), |(mal_id, viewed)| {
+            if let Some((title, img)) = viewed {
+                log!("Setting title to:", title);
+                web_sys::window().unwrap().document().unwrap().set_title(&format!("{} | ANiNFO", title));
+                record_recently_viewed(*mal_id as u32, title.clone(), img.clone());
+            }
+            || {}
+        });
+    }
 
+    let html_result = match &(*result) {
+        (Ok(ao), Ok(co), Ok(ro), listed_episodes) => {
+            let title = display_title(&cx, &(ao.data.clone().into()));
+            // Airing shows have no listed count, so use `EpisodeCards`' once it has one
+            let shown_episodes = match listed_episodes {
+                Some(n) => *n,
+                None => Some(&*episodes)
+                    .filter(|store| store.mal_id == mal_id)
+                    .and_then(|store| store.total(None))
+                    .unwrap_or(0),
+            };
             let add_to_favourite = {
                 let ttl_en = ao.data.title_english.clone();
                 let ttl_jp = ao.data.title_japanese.clone();
                 let img_link = ao.data.images.webp.large_image_url.clone();
-                let jwt = cx.jwt.clone();
                 let uuid = cx.uuid.clone();
                 let mal_id = props.mal_id.clone();
                 let cx = cx.clone();
@@ -256,24 +234,21 @@ pub fn content(props: &Props) -> HtmlResult {
                     let img_link = img_link.clone();
                     let uuid = uuid.clone();
                     let mal_id = mal_id.clone();
-                    let jwt = jwt.clone();
                     let cx = cx.clone();
                     wasm_bindgen_futures::spawn_local(
                         async move {
-                            let result = reqwasm::http::Request::post(&format!("{}/api/v1/users/add_anime", BASE_URL))
-                                .header("Authorization", &format!("Bearer {}", jwt.unwrap()))
-                                .header("Content-Type", "application/json")
-                                .body(serde_json::to_string(
-                                    &UserAnimeSubmission {
-                                        uuid: uuid.unwrap(),
-                                        anime_id: mal_id as i32,
-                                        anime_img: img_link.clone(),
-                                        anime_ttl_en: ttl_en.clone().unwrap(),
-                                        anime_ttl_jp: ttl_jp.clone(),
-                                    }
-                                ).unwrap()).send().await.unwrap();
+                            let result = AuthClient::new(&cx).post_json(
+                                &format!("{}/api/v1/users/add_anime", BASE_URL),
+                                &UserAnimeSubmission {
+                                    uuid: uuid.unwrap(),
+                                    anime_id: mal_id as i32,
+                                    anime_img: img_link.clone(),
+                                    anime_ttl_en: ttl_en.clone().unwrap(),
+                                    anime_ttl_jp: ttl_jp.clone(),
+                                }
+                            ).await;
 
-                            if result.status() == 200 {
+                            if result.as_ref().map(|r| r.status() == 200).unwrap_or(false) {
                                 let mut v =  (*cx).fav_anime.clone().unwrap().into_iter().map(|ua_response| {
                                     ua_response.anime_id
                                 }).collect::<Vec<i32>>();
@@ -291,11 +266,11 @@ pub fn content(props: &Props) -> HtmlResult {
                                 }))
                                     .update_fav_anime_id_into(Some(
                                         v
-                                    ))
+                                    )).into()
                                 );
-                                    alert("Successfully added this anime to Favourites!");
+                                    alert(&t!("details.fav_added"));
                                 } else {
-                                    alert("Failed adding this anime to Favourites. Please retry in a few moments.");
+                                    alert(&t!("details.fav_add_failed"));
                                 }
                         }
                     );
@@ -303,22 +278,19 @@ pub fn content(props: &Props) -> HtmlResult {
             };
 
             let remove_from_favourite = {
-                let jwt = cx.jwt.clone();
                 let cx = cx.clone();
                 let uuid = cx.uuid.clone();
                 let mal_id = props.mal_id.clone();
                 Callback::from(move |_: MouseEvent| {
                     let uuid = uuid.clone();
                     let mal_id = mal_id.clone();
-                    let jwt = jwt.clone();
                     let cx = cx.clone();
                     wasm_bindgen_futures::spawn_local(
                         async move {
-                            let result = reqwasm::http::Request::post(&format!("{}/api/v1/users/remove_anime/{}", BASE_URL, mal_id))
-                                .header("Authorization", &format!("Bearer {}", jwt.unwrap()))
-                                .header("Content-Type", "application/json")
-                                .send().await.unwrap();
-                            if result.status() == 200 {
+                            let result = AuthClient::new(&cx)
+                                .post(&format!("{}/api/v1/users/remove_anime/{}", BASE_URL, mal_id))
+                                .await;
+                            if result.as_ref().map(|r| r.status() == 200).unwrap_or(false) {
                                 let mut v =  (*cx).fav_anime.clone().unwrap().into_iter().filter_map(|ua_response| {
                                     if ua_response.anime_id == mal_id as i32 {
                                         None
@@ -337,11 +309,11 @@ pub fn content(props: &Props) -> HtmlResult {
                                     }).collect::<Vec<UserAnimeResponse>>()))
                                     .update_fav_anime_id_into(Some(
                                         v
-                                    ))
+                                    )).into()
                                 );
-                                alert("Successfully removed this anime to Favourites!");
+                                alert(&t!("details.fav_removed"));
                             } else {
-                                alert("Failed removing this anime to Favourites. Please retry in a few moments.");
+                                alert(&t!("details.fav_remove_failed"));
                             }
                         }
                     );
@@ -354,11 +326,11 @@ pub fn content(props: &Props) -> HtmlResult {
 
                             // LARGE SCREEN
                         <div class={format!("anime-details-header {}", theme)}>
-                            <h1 class="anime-details-title only-small-screen">{handle_title_language(&cx, &(ao.data.clone().into()))}</h1>
-                            // <h1 class="anime-details-title only-large-screen">{handle_title_language(&cx, &(ao.data))}</h1>
+                            <h1 class="anime-details-title only-small-screen">{&title}</h1>
+                            // <h1 class="anime-details-title only-large-screen">{display_title(&cx, &(ao.data))}</h1>
                             <img loading="lazy" class="only-large-screen" id="anime-details-header-cover" src={extract_img_from_cx(&(ao.data.clone().into()))}/>
                             <div class="anime-details-header-info only-large-screen">
-                                <h1 class="anime-details-title">{handle_title_language(&cx, &(ao.data.clone().into()))}</h1>
+                                <h1 class="anime-details-title">{&title}</h1>
                                 <article class="anime-meta">
                                 {self::handle_year(ao.data.year.clone())}
                                 {
@@ -368,7 +340,7 @@ pub fn content(props: &Props) -> HtmlResult {
                                         html!{}
                                     }
                                 }
-                                {self::handle_eps(*n)}
+                                {self::handle_eps(shown_episodes)}
                                 {self::handle_rating_html(ao.data.score.clone(), ao.data.scored_by.clone())}
                                 {self::handle_studios(ao.data.studios.clone())}
                                 {self::handle_anime_theme(ao.data.themes.clone())}
@@ -380,7 +352,7 @@ pub fn content(props: &Props) -> HtmlResult {
                                             let genre_name = genre_obj.name.clone();
                                             move |_| {
                                                 nav.push(&Route::AnimeResultStd {
-                                                    content_title: format!("Top {} Anime", genre_name),
+                                                    content_title: t!("details.top_genre", genre = genre_name),
                                                     page: 1,
                                                     url: StdResultType::Genre(genre_obj.mal_id)})
                                             }
@@ -402,7 +374,7 @@ pub fn content(props: &Props) -> HtmlResult {
                                     } else {
                                         html! {
                                             <a onclick={|_| {
-                                                alert("You need to be logged in to add an anime to Favourites.");
+                                                alert(&t!("details.fav_login_required"));
                                             }}><img id="fav-icon" src="./static/heart.svg" width="28px" height="28px" class="icon-dark cursor-disabled"/></a>
                                         }
                                     }
@@ -414,7 +386,7 @@ pub fn content(props: &Props) -> HtmlResult {
                             <div class="info-img-wrapper only-small-screen">
                                 <img loading="lazy" id="anime-details-header-cover" src={extract_img_from_cx(&(ao.data.clone().into()))}/>
                                 <div class="anime-details-header-info">
-                                    <h1 class="anime-details-title">{handle_title_language(&cx, &(ao.data.clone().into()))}</h1>
+                                    <h1 class="anime-details-title">{&title}</h1>
                                     {self::handle_year(ao.data.year.clone())}
                                     {
                                         if let Some(AnimeStatus::NotYetAiring) = ao.data.status {
@@ -446,7 +418,7 @@ pub fn content(props: &Props) -> HtmlResult {
                                         } else {
                                             html! {
                                                 <a onclick={|_| {
-                                                    alert("You need to be logged in to add an anime to Favourites.");
+                                                    alert(&t!("details.fav_login_required"));
                                                 }}><img id="fav-icon" src="./static/heart.svg" width="28px" height="28px" class="icon-dark cursor-disabled"/></a>
                                             }
                                         }
@@ -462,26 +434,26 @@ pub fn content(props: &Props) -> HtmlResult {
                         <div class={format!("streams-wrapper {}", theme)}>
                         <div class="ad-section-header">
                         <img loading="lazy" id="streams-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/stream_download.svg"/>
-                        <h2 id="ad-section-header" class="content-ttl">{"Stream / Download"}</h2></div>
+                        <h2 id="ad-section-header" class="content-ttl">{t!("details.stream_download")}</h2></div>
                             <div class="streams-btn-wrapper">
-                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://nyaa.si/?f=0&c=1_0&q={}&s=seeders&o=desc", ao.data.titles[0].clone().title)}>
-                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/nyaa_light.png"/><p class="stream-caption"><span class="font-weight-150">{"Download from "}</span><b>{"nyaa.si"}</b></p></a>
-                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://kayoanime.com/?s={}", ao.data.titles[0].clone().title)}>
-                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/kayoanime.png"/><p class="stream-caption"><span class="font-weight-150">{"Download from "}</span><b>{"Kayoanime"}</b></p></a>
-                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://aniwave.to/filter?keyword={}", ao.data.titles[0].clone().title)}>
-                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/aw_light.png"/><p class="stream-caption"><span class="font-weight-150">{"Stream on"}</span><b>{" AniWave"}</b></p></a>
-                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://anix.to/filter?keyword={}", ao.data.titles[0].clone().title)}>
-                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/anix.png"/><p class="stream-caption"><span class="font-weight-150">{"Stream on "}</span><b>{"Anix"}</b></p></a>
-                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://zorotv.com.in/?s={}", ao.data.titles[0].clone().title)}>
-                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/zoro.png"/><p class="stream-caption"><span class="font-weight-150">{"Stream on "}</span><b>{"Zoro"}</b></p></a>
+                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://nyaa.si/?f=0&c=1_0&q={}&s=seeders&o=desc", &title)}>
+                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/nyaa_light.png"/><p class="stream-caption"><span class="font-weight-150">{t!("details.download_from")}</span><b>{"nyaa.si"}</b></p></a>
+                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://kayoanime.com/?s={}", &title)}>
+                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/kayoanime.png"/><p class="stream-caption"><span class="font-weight-150">{t!("details.download_from")}</span><b>{"Kayoanime"}</b></p></a>
+                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://aniwave.to/filter?keyword={}", &title)}>
+                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/aw_light.png"/><p class="stream-caption"><span class="font-weight-150">{t!("details.stream_on")}</span><b>{"AniWave"}</b></p></a>
+                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://anix.to/filter?keyword={}", &title)}>
+                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/anix.png"/><p class="stream-caption"><span class="font-weight-150">{t!("details.stream_on")}</span><b>{"Anix"}</b></p></a>
+                            <a class={format!("stream-btn hover-highlight {}", theme)} target="_blank" rel="noopener noreferrer" href={format!("https://zorotv.com.in/?s={}", &title)}>
+                            <img loading="lazy" class={format!("icon-{}", theme)} src="./static/zoro.png"/><p class="stream-caption"><span class="font-weight-150">{t!("details.stream_on")}</span><b>{"Zoro"}</b></p></a>
                             </div>
                         </div>
 
                         <div class="characters-wrapper" id="characters-wrapper">
                             <div class="ad-section-header">
-                            <img id="characters-icon" loading="lazy" class={format!("ad-section-icon icon-{} hideable", theme.clone())} src="./static/character.png"/><h2 id="ad-section-header" class="content-ttl">{"Characters"}</h2></div>
+                            <img id="characters-icon" loading="lazy" class={format!("ad-section-icon icon-{} hideable", theme.clone())} src="./static/character.png"/><h2 id="ad-section-header" class="content-ttl">{t!("details.characters")}</h2></div>
                             {if (co.data).len() == 0 {
-                                html!{<span class="no-result">{"Character data is unavailable."}</span>}
+                                html!{<span class="no-result">{t!("details.characters_unavailable")}</span>}
                             } else {
                                 html!{
                                     <div class="char-card-styler">
@@ -497,24 +469,24 @@ pub fn content(props: &Props) -> HtmlResult {
                         <EpisodeCards
                             anime_ttl_def={ao.data.titles[0].title.clone()}
                             anime_ttl_en={ao.data.title_english.clone().unwrap_or(ao.data.titles[0].title.clone())}
-                            eo={eo.data.clone()}
-                            eps_total={*n as usize}
-                            total_pages = {eo.pagination.last_visible_page as usize}
+                            listed_episodes={*listed_episodes}
                             mal_id={ao.data.mal_id}
                         />
 
+                        <AnimePictures mal_id={ao.data.mal_id} title={title.clone()}/>
+
                         <section class="themesongs-wrapper">
                         <div class="ad-section-header">
-                        <img loading="lazy" id="themesong-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/music.png"/><h2 id="ad-section-header" class="content-ttl">{"Theme Songs"}</h2></div>
+                        <img loading="lazy" id="themesong-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/music.png"/><h2 id="ad-section-header" class="content-ttl">{t!("details.theme_songs")}</h2></div>
                         {if ao.data.theme.openings.len() == 0 && ao.data.theme.endings.len() == 0 {
-                            html!{<span class="no-result">{"Theme song data is unavailable."}</span>}
+                            html!{<span class="no-result">{t!("details.theme_songs_unavailable")}</span>}
                         } else {
                             html!{
                                 <>
-                                <h3 class="content-subttl">{"Openings"}</h3>
+                                <h3 class="content-subttl">{t!("details.openings")}</h3>
                                 <div class="themesongs-card-wrapper cursor-arrow">
                                 {if ao.data.theme.openings.len() == 0 {
-                                    html!{{"Anime opening data is unavailable."}}
+                                    html!{{t!("details.openings_unavailable")}}
                                 } else {
                                     ao.data.theme.openings.iter().enumerate().map(|(i, s)| {
                                         if let Ok(r) = parse_themesong(s) {
@@ -559,10 +531,10 @@ pub fn content(props: &Props) -> HtmlResult {
                                     }).collect::<Html>()
                                 }}
                                 </div>
-                                <h3 class="content-subttl">{"Endings"}</h3>
+                                <h3 class="content-subttl">{t!("details.endings")}</h3>
                                 <div class="themesongs-card-wrapper cursor-arrow">
                                 {if ao.data.theme.endings.len() == 0 {
-                                    html!{{"Anime ending data is unavailable."}}
+                                    html!{{t!("details.endings_unavailable")}}
                                 } else {
                                     ao.data.theme.endings.iter().enumerate().map(|(i, s)| {
                                         if let Ok(r) = parse_themesong(s) {
@@ -615,7 +587,7 @@ pub fn content(props: &Props) -> HtmlResult {
 
                         <div class="recommendation-wrapper" id="recommendation-wrapper">
                         <div class="ad-section-header">
-                        <img loading="lazy" id="recommendation-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/download.png"/><h2 id="ad-section-header" class="content-ttl">{"Similar Anime"}</h2></div>
+                        <img loading="lazy" id="recommendation-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/download.png"/><h2 id="ad-section-header" class="content-ttl">{t!("details.similar")}</h2></div>
                         {into_anime_cards_minimal(&(ro.data))}
                         </div>
 
@@ -625,7 +597,7 @@ pub fn content(props: &Props) -> HtmlResult {
                 </>
             }
         }
-        _ => html! {"Uncatched exception."},
+        _ => html! {t!("details.load_failed")},
     };
 
     Ok(html!({ html_result }))
@@ -53,15 +53,15 @@ fn content(props: &StdResultProp) -> HtmlResult {
                 .unwrap()
                 .document()
                 .unwrap()
-                .set_title(format!("No Result | ANiNFO").as_str());
+                .set_title(&format!("{} | ANiNFO", t!("search.no_result_title")));
             if v.data.len() == 0 {
                 html! {
                     <div class="search-result-wrapper">
                     <div class="written-content">
                         <div class="content-header">
-                            <span><h2 class="content-ttl vc-text">{"Oops!"}</h2></span>
+                            <span><h2 class="content-ttl vc-text">{t!("search.oops")}</h2></span>
                         </div>
-                        <p style="text-align: center !important;">{"No anime matching your search query is found."}</p>
+                        <p style="text-align: center !important;">{t!("search.no_match")}</p>
                     </div>
                     </div>
                 }
@@ -76,11 +76,27 @@ fn content(props: &StdResultProp) -> HtmlResult {
                         <div class="content-header">
                             <span><h2 class="content-ttl vc-text">{&(props.content_title)}</h2></span>
                         </div>
-                        <PageButtonsStd pagination={(*v).pagination.clone()} url={props.url.clone()} content_title={props.content_title.clone()} page={props.page}/>
-                        <div class="cards-wrapper">
-                            {into_anime_cards(&(v.data))}
-                        </div>
-                        <PageButtonsStd pagination={(*v).pagination.clone()} url={props.url} content_title={props.content_title.clone()} page={props.page}/>
+                        {
+                            if (*cx).scroll_mode == ScrollMode::Infinite {
+                                html!{
+                                    <InfiniteAnimeGrid
+                                        list_key={format!("{}:{}", props.url.link(), (*cx).nsfw)}
+                                        base_link={format!("{}&sfw={}", props.url.link(), !(*cx).nsfw)}
+                                        first_page={v.clone()}
+                                    />
+                                }
+                            } else {
+                                html!{
+                                    <>
+                                    <PageButtonsStd pagination={(*v).pagination.clone()} url={props.url.clone()} content_title={props.content_title.clone()} page={props.page}/>
+                                    <div class="cards-wrapper">
+                                        {into_anime_cards(&(v.data))}
+                                    </div>
+                                    <PageButtonsStd pagination={(*v).pagination.clone()} url={props.url.clone()} content_title={props.content_title.clone()} page={props.page}/>
+                                    </>
+                                }
+                            }
+                        }
                     </div>
                 }
             }
@@ -18,6 +18,44 @@ pub enum Language {
     JP,
 }
 
+impl Language {
+    /// Catalog the UI strings come from, also used as the document's `lang`.
+    pub fn locale(&self) -> &'static str {
+        match self {
+            Language::EN => "en",
+            Language::JP => "ja",
+        }
+    }
+}
+
+/// How result lists move past their first page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default, Hash, Copy)]
+pub enum ScrollMode {
+    #[default]
+    Paged,
+    Infinite,
+}
+
+impl Theme {
+    /// The theme the toggle switches to: Light → Dark → Auto → Light.
+    pub fn next(self) -> Theme {
+        match self {
+            Theme::Light => Theme::Dark,
+            Theme::Dark => Theme::Auto,
+            Theme::Auto => Theme::Light,
+        }
+    }
+
+    /// Light or Dark, with Auto following the OS preference.
+    pub fn resolve(self, prefers_dark: bool) -> Theme {
+        match (self, prefers_dark) {
+            (Theme::Auto, true) => Theme::Dark,
+            (Theme::Auto, false) => Theme::Light,
+            (theme, _) => theme,
+        }
+    }
+}
+
 pub const LIGHT_THEME: Theme = Theme::Light;
 pub const DARK_THEME: Theme = Theme::Dark;
 pub const AUTO_THEME: Theme = Theme::Auto;
@@ -29,21 +67,54 @@ pub struct AppCtx {
     pub fav_anime: Option<Vec<UserAnimeResponse>>,
     pub fav_anime_id: Option<Vec<i32>>,
     pub uuid: Option<i32>,
+    pub role: UserRole,
     pub theme: Theme,
+    /// Whether the OS asks for a dark color scheme, which is what `Theme::Auto` follows.
+    pub prefers_dark: bool,
     pub language: Language,
+    /// Title kinds to try, in order, when showing an anime's name.
+    pub title_order: Vec<TitleKind>,
     pub cur_page: u8,
     pub loading_page: bool,
     pub nsfw: bool,
+    pub scroll_mode: ScrollMode,
     pub query: String,
     pub cache: Cache,
     hash: u64,
 }
 
+pub enum AppAction {
+    /// Replaces the whole state, for updates built from the state just rendered.
+    Replace(AppCtx),
+    /// Only the access token. Refreshes finish long after the state they started
+    /// from, so replacing everything would revert changes made in the meantime.
+    SetJwt(String),
+    /// Drops the user's data, leaving settings and caches alone.
+    ClearSession,
+}
+
+impl From<AppCtx> for AppAction {
+    fn from(ctx: AppCtx) -> Self {
+        AppAction::Replace(ctx)
+    }
+}
+
 impl Reducible for AppCtx {
-    type Action = AppCtx;
+    type Action = AppAction;
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
-        action.into()
+        match action {
+            AppAction::Replace(ctx) => ctx.into(),
+            AppAction::SetJwt(jwt) => self.update_jwt_into(Some(jwt)).into(),
+            AppAction::ClearSession => self
+                .update_jwt_into(None)
+                .update_username_into(None)
+                .update_uuid_into(None)
+                .update_role_into(UserRole::User)
+                .update_fav_anime_into(None)
+                .update_fav_anime_id_into(None)
+                .into(),
+        }
     }
 }
 
@@ -65,6 +136,24 @@ impl AppCtx {
         }
     }
 
+    pub fn update_prefers_dark_into(&self, prefers_dark: bool) -> AppCtx {
+        AppCtx {
+            uuid: (&self.uuid).clone(),
+            jwt: (&self.jwt).clone(),
+            username: (&self.username).clone(),
+            theme: (&self.theme).clone(),
+            prefers_dark,
+            language: (&self.language).clone(),
+            cur_page: (&self).cur_page,
+            loading_page: (&self).loading_page,
+            nsfw: (&self).nsfw,
+            query: (*self).query.clone(),
+            cache: (&self.cache).clone(),
+            hash: (&self).hash,
+            ..self.clone()
+        }
+    }
+
     pub fn update_language_into(&self, new_lang: Language) -> AppCtx {
         AppCtx {
             uuid: (&self.uuid).clone(),
@@ -82,6 +171,24 @@ impl AppCtx {
         }
     }
 
+    pub fn update_title_order_into(&self, title_order: Vec<TitleKind>) -> AppCtx {
+        AppCtx {
+            uuid: (&self.uuid).clone(),
+            jwt: (&self.jwt).clone(),
+            username: (&self.username).clone(),
+            theme: (&self.theme).clone(),
+            language: (&self.language).clone(),
+            title_order,
+            cur_page: (&self).cur_page,
+            loading_page: (&self).loading_page,
+            nsfw: (&self).nsfw,
+            query: (*self).query.clone(),
+            cache: (&self.cache).clone(),
+            hash: (&self).hash,
+            ..self.clone()
+        }
+    }
+
     pub fn update_page_into(&self, new_page: u8) -> AppCtx {
         let ctx = AppCtx {
             uuid: (&self.uuid).clone(),
@@ -135,6 +242,43 @@ impl AppCtx {
         ctx
     }
 
+    pub fn update_scroll_mode_into(&self, scroll_mode: ScrollMode) -> AppCtx {
+        AppCtx {
+            uuid: (&self.uuid).clone(),
+            jwt: (&self.jwt).clone(),
+            username: (&self.username).clone(),
+            theme: (&self.theme).clone(),
+            language: (&self).language.clone(),
+            cur_page: (&self).cur_page,
+            loading_page: (&self).loading_page,
+            nsfw: (&self).nsfw,
+            scroll_mode,
+            query: (*self).query.clone(),
+            cache: (&self.cache).clone(),
+            hash: (&self).hash,
+            ..self.clone()
+        }
+    }
+
+    pub fn update_settings_into(&self, settings: &UserSettings) -> AppCtx {
+        AppCtx {
+            uuid: (&self.uuid).clone(),
+            jwt: (&self.jwt).clone(),
+            username: (&self.username).clone(),
+            theme: settings.theme,
+            language: settings.language,
+            title_order: settings.title_order.clone(),
+            cur_page: (&self).cur_page,
+            loading_page: (&self).loading_page,
+            nsfw: settings.nsfw,
+            scroll_mode: settings.scroll_mode,
+            query: (*self).query.clone(),
+            cache: (&self.cache).clone(),
+            hash: (&self).hash,
+            ..self.clone()
+        }
+    }
+
     pub fn update_query_into(&self, query: String) -> AppCtx {
         let ctx = AppCtx {
             uuid: (&self.uuid).clone(),
@@ -239,6 +383,24 @@ impl AppCtx {
         }
     }
 
+    pub fn update_role_into(&self, role: UserRole) -> AppCtx {
+        AppCtx {
+            uuid: (&self.uuid).clone(),
+            jwt: (&self.jwt).clone(),
+            username: (&self.username).clone(),
+            role,
+            theme: (&self.theme).clone(),
+            language: (&self).language.clone(),
+            cur_page: (&self).cur_page,
+            loading_page: (&self).loading_page,
+            nsfw: (&self).nsfw,
+            query: (&self.query).clone(),
+            cache: (&self).cache.clone(),
+            hash: (&self).hash,
+            ..(self.clone())
+        }
+    }
+
     pub fn update_fav_anime_into(&self, fav_anime: Option<Vec<UserAnimeResponse>>) -> AppCtx {
         AppCtx {
             jwt: (&self.jwt).clone(),
@@ -306,17 +468,23 @@ pub struct AppContextProviderProps {
 
 #[function_component(AppContextProvider)]
 pub fn app(props: &AppContextProviderProps) -> Html {
+    // Read synchronously so the very first render already has the right theme
+    let settings = load_local_settings().unwrap_or_default();
     let init_ctx = AppCtx {
         uuid: None,
         jwt: None,
         fav_anime: None,
         fav_anime_id: None,
         username: None,
-        theme: DARK_THEME,
-        language: Language::EN,
+        role: UserRole::User,
+        theme: settings.theme,
+        prefers_dark: system_prefers_dark(),
+        language: settings.language,
+        title_order: settings.title_order.clone(),
         cur_page: 1,
         loading_page: false,
-        nsfw: false,
+        nsfw: settings.nsfw,
+        scroll_mode: settings.scroll_mode,
         query: "".to_string(),
         cache: Cache {
             anime_details: None,
@@ -331,6 +499,50 @@ pub fn app(props: &AppContextProviderProps) -> Html {
 
     let app_ctx = use_reducer(|| init_ctx);
 
+    {
+        let app_ctx = app_ctx.clone();
+        use_effect_with(UserSettings::from_ctx(&app_ctx), move |settings| {
+            persist_settings(&app_ctx, settings);
+            || {}
+        });
+    }
+
+    {
+        let settings = UserSettings::from_ctx(&app_ctx);
+        use_effect_with((settings, app_ctx.prefers_dark), move |(settings, prefers_dark)| {
+            apply_document_settings(settings, *prefers_dark);
+            || {}
+        });
+    }
+
+    // Follow OS theme changes live. The listener only flips local state; the context
+    // is updated from a render so it is never dispatched from a stale handle.
+    let system_dark = use_state(system_prefers_dark);
+    {
+        let system_dark = system_dark.clone();
+        use_effect_once(move || {
+            let listener = prefers_dark_query().map(|mql| {
+                let query = mql.clone();
+                gloo::events::EventListener::new(&mql, "change", move |_| {
+                    system_dark.set(query.matches());
+                })
+            });
+            move || drop(listener)
+        });
+    }
+    {
+        let app_ctx = app_ctx.clone();
+        use_effect_with(*system_dark, move |dark| {
+            if app_ctx.prefers_dark != *dark {
+                app_ctx.dispatch(app_ctx.update_prefers_dark_into(*dark).into());
+            }
+            || {}
+        });
+    }
+
+    // Children render after this, so every `t!` below already sees the new language
+    set_locale(app_ctx.language.locale());
+
     let app_ctx_cloned = app_ctx.clone();
 
     html! {
@@ -9,6 +9,17 @@ pub struct CharacterCardProp {
 pub fn char_card(props: &CharacterCardProp) -> Html {
     let cx = use_context::<AppContext>().unwrap();
     let theme = handle_theme(&cx);
+    let show_pictures = use_state(|| false);
+    let character = &props.char.character;
+
+    let open_pictures = {
+        let show_pictures = show_pictures.clone();
+        Callback::from(move |_: MouseEvent| show_pictures.set(true))
+    };
+    let close_pictures = {
+        let show_pictures = show_pictures.clone();
+        Callback::from(move |_: ()| show_pictures.set(false))
+    };
 
     html! {
         <div class={format!("char-card hover-highlight {}", theme)}>
@@ -16,6 +27,11 @@ pub fn char_card(props: &CharacterCardProp) -> Html {
             src={props.char.character.get_image_webp().unwrap_or(props.char.character.get_images_jpg().unwrap())} class="char-img"/></a>
             <a target="_blank" href={props.char.character.url.clone()}><h5 class="char-title cursor-pointer">{handle_long_name(props.char.character.get_name().as_str())}</h5></a>
             <p class="char-role">{props.char.role.clone()}</p>
+            <a class="a-btn u_onhover cursor-pointer char-pictures" onclick={open_pictures}>{t!("gallery.title")}</a>
+            if *show_pictures {
+                <CharacterPictures char_id={character.mal_id as u64} name={character.get_name()}
+                    onclose={close_pictures}/>
+            }
         </div>
     }
 }
@@ -1,7 +1,7 @@
-use std::error::Error;
+use std::{error::Error, rc::Rc};
 
-use gloo::dialogs::alert;
-use web_sys::{js_sys::Math::random, HtmlDialogElement, HtmlInputElement};
+use gloo::dialogs::{alert, confirm};
+use web_sys::{HtmlDialogElement, HtmlInputElement, HtmlTextAreaElement};
 
 use crate::prelude::*;
 
@@ -10,20 +10,498 @@ pub struct CommentProps {
     pub anime_id: i32,
 }
 
+#[derive(Properties, PartialEq)]
+pub struct MdSpoilerProps {
+    pub children: Html,
+}
+
+/// `||spoiler||` text, blurred until clicked.
+#[function_component]
+pub fn MdSpoiler(props: &MdSpoilerProps) -> Html {
+    let revealed = use_state(|| false);
+    let reveal = {
+        let revealed = revealed.clone();
+        Callback::from(move |_: MouseEvent| revealed.set(true))
+    };
+    let class = if *revealed { "md-spoiler revealed" } else { "md-spoiler" };
+
+    html! {
+        <span {class} title={t!("comments.spoiler")} onclick={reveal}>{props.children.clone()}</span>
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CommentMode {
+    Viewing,
+    Replying,
+    Editing,
+    Reporting,
+}
+
+#[derive(Properties, PartialEq)]
+struct CommentItemProps {
+    thread: CommentThread,
+    anime_id: i32,
+    depth: usize,
+    /// Reloads the list, after something that changes its shape.
+    on_changed: Callback<()>,
+    /// Replaces a single comment in the list, e.g. after a reaction.
+    on_updated: Callback<AnimeCommentGet>,
+}
+
+/// One comment with its reply form, author and moderator actions and, below it, its
+/// replies.
 #[function_component]
-fn Content(props: &CommentProps) -> HtmlResult {
+fn CommentItem(props: &CommentItemProps) -> Html {
     let cx = use_context::<AppContext>().unwrap();
     let theme = handle_theme(&cx);
-    let anime_id = props.anime_id;
+    let comment = props.thread.comment.clone();
+    let mode = use_state(|| CommentMode::Viewing);
+    let draft = use_state(String::new);
+    let report_reason = use_state(|| ReportReason::Spam);
+    let (block_list, block_dispatch) = use_store::<BlockList>();
     let nav = use_navigator().unwrap();
-    let comment_status = use_state(|| random());
+    let is_author = is_comment_author(&cx, &comment);
+    let is_moderator = cx.jwt.is_some() && cx.role.is_moderator();
 
-    let result = use_future_with(anime_id, |_| async move {
-        let url = format!("{}/api/v1/anime/{}/comment", BASE_URL, anime_id);
-        let comments = force_req::<Vec<AnimeCommentGet>>(&url).await;
+    let open = {
+        let mode = mode.clone();
+        let draft = draft.clone();
+        let body = comment.comment.clone();
+        move |next: CommentMode| {
+            let mode = mode.clone();
+            let draft = draft.clone();
+            let body = body.clone();
+            Callback::from(move |_: MouseEvent| {
+                draft.set(if next == CommentMode::Editing { body.clone() } else { String::new() });
+                mode.set(next);
+            })
+        }
+    };
+
+    let cancel = {
+        let mode = mode.clone();
+        Callback::from(move |_: MouseEvent| mode.set(CommentMode::Viewing))
+    };
 
-        comments
-    })?;
+    let draft_oninput = {
+        let draft = draft.clone();
+        Callback::from(move |e: InputEvent| {
+            draft.set(e.target_unchecked_into::<HtmlTextAreaElement>().value());
+        })
+    };
+
+    let report = {
+        let cx = cx.clone();
+        let mode = mode.clone();
+        let draft = draft.clone();
+        Callback::from(move |_: MouseEvent| {
+            if cx.jwt.is_none() {
+                alert(&t!("report.login_required"));
+                return;
+            }
+            draft.set(String::new());
+            mode.set(CommentMode::Reporting);
+        })
+    };
+
+    let toggle_block = {
+        let username = comment.username.clone();
+        Callback::from(move |_: MouseEvent| {
+            block_dispatch.reduce_mut(|b| b.toggle(&username));
+            save_blocked_users(&block_dispatch.get().usernames);
+        })
+    };
+
+    let open_profile = {
+        let username = comment.username.clone();
+        Callback::from(move |_: MouseEvent| {
+            nav.push(&Route::UserProfile {
+                username: username.clone(),
+            });
+        })
+    };
+
+    let submit = {
+        let cx = cx.clone();
+        let mode = mode.clone();
+        let draft = draft.clone();
+        let report_reason = report_reason.clone();
+        let on_changed = props.on_changed.clone();
+        let anime_id = props.anime_id;
+        let comment_id = comment.id;
+        // Replies stay in the discussion of the same episode
+        let episode = comment.episode;
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let text = draft.trim().to_string();
+            let current = *mode;
+            // Details are optional for reports only
+            if text.is_empty() && current != CommentMode::Reporting {
+                return;
+            }
+            let cx = cx.clone();
+            let mode = mode.clone();
+            let on_changed = on_changed.clone();
+            let reason = *report_reason;
+            wasm_bindgen_futures::spawn_local(async move {
+                match current {
+                    CommentMode::Editing => {
+                        let result = AuthClient::new(&cx)
+                            .put_json(
+                                &format!("{}/api/v1/comment/{}", BASE_URL, comment_id),
+                                &AnimeCommentEdit { comment: text },
+                            )
+                            .await;
+                        match result {
+                            Ok(r) if r.ok() => {
+                                mode.set(CommentMode::Viewing);
+                                on_changed.emit(());
+                            }
+                            _ => alert(&t!("comments.edit_failed")),
+                        }
+                    }
+                    CommentMode::Reporting => {
+                        let report = CommentReport {
+                            reason,
+                            details: (!text.is_empty()).then_some(text),
+                        };
+                        match report_comment(&cx, comment_id, &report).await {
+                            Ok(_) => {
+                                mode.set(CommentMode::Viewing);
+                                alert(&t!("report.sent"));
+                            }
+                            Err(e) => {
+                                log!(format!("{:?}", e));
+                                alert(&t!("report.failed"));
+                            }
+                        }
+                    }
+                    _ => {
+                        let reply = AnimeCommentPost {
+                            comment: text,
+                            parent_id: Some(comment_id),
+                            episode,
+                        };
+                        match post_comment(&cx, anime_id, &reply).await {
+                            Ok(_) => {
+                                mode.set(CommentMode::Viewing);
+                                on_changed.emit(());
+                            }
+                            Err(e) => alert(&e.message()),
+                        }
+                    }
+                }
+            });
+        })
+    };
+
+    let delete = {
+        let cx = cx.clone();
+        let on_changed = props.on_changed.clone();
+        let comment_id = comment.id;
+        Callback::from(move |_: MouseEvent| {
+            if !confirm(&t!("comments.delete_confirm")) {
+                return;
+            }
+            let cx = cx.clone();
+            let on_changed = on_changed.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let url = format!("{}/api/v1/comment/{}", BASE_URL, comment_id);
+                match AuthClient::new(&cx).delete(&url).await {
+                    Ok(r) if r.ok() => on_changed.emit(()),
+                    _ => alert(&t!("comments.delete_failed")),
+                }
+            });
+        })
+    };
+
+    let toggle_hidden = {
+        let cx = cx.clone();
+        let comment = comment.clone();
+        let on_updated = props.on_updated.clone();
+        Callback::from(move |_: MouseEvent| {
+            let cx = cx.clone();
+            let comment = comment.clone();
+            let on_updated = on_updated.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match set_comment_hidden(&cx, comment.id, !comment.hidden).await {
+                    Ok(_) => on_updated.emit(AnimeCommentGet {
+                        hidden: !comment.hidden,
+                        ..comment
+                    }),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        alert(&t!("moderation.failed"));
+                    }
+                }
+            });
+        })
+    };
+
+    let remove = {
+        let cx = cx.clone();
+        let on_changed = props.on_changed.clone();
+        let comment_id = comment.id;
+        Callback::from(move |_: MouseEvent| {
+            if !confirm(&t!("moderation.remove_confirm")) {
+                return;
+            }
+            let cx = cx.clone();
+            let on_changed = on_changed.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match remove_comment(&cx, comment_id).await {
+                    Ok(_) => on_changed.emit(()),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        alert(&t!("moderation.failed"));
+                    }
+                }
+            });
+        })
+    };
+
+    let react = {
+        let cx = cx.clone();
+        let comment = comment.clone();
+        let on_updated = props.on_updated.clone();
+        move |clicked: Reaction| {
+            let cx = cx.clone();
+            let comment = comment.clone();
+            let on_updated = on_updated.clone();
+            Callback::from(move |_: MouseEvent| {
+                if cx.jwt.is_none() {
+                    alert(&t!("comments.login_required_react"));
+                    return;
+                }
+                let reaction = next_reaction(comment.my_reaction, clicked);
+                on_updated.emit(apply_reaction(&comment, reaction));
+
+                let cx = cx.clone();
+                let comment = comment.clone();
+                let on_updated = on_updated.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(e) = send_reaction(&cx, comment.id, reaction).await {
+                        log!(format!("{:?}", e));
+                        on_updated.emit(comment);
+                        alert(&t!("comments.reaction_failed"));
+                    }
+                });
+            })
+        }
+    };
+    let reaction_class = |r: Reaction| {
+        if comment.my_reaction == Some(r) {
+            "a-btn reaction active"
+        } else {
+            "a-btn reaction"
+        }
+    };
+
+    let form = match *mode {
+        CommentMode::Viewing => html! {},
+        CommentMode::Reporting => html! {
+            <form class="comment-inline-form" onsubmit={submit}>
+                <span>{t!("report.title")}</span>
+                <div class="report-reasons">
+                {
+                    ReportReason::ALL.iter().map(|reason| {
+                        let reason = *reason;
+                        let report_reason = report_reason.clone();
+                        let class = if *report_reason == reason { "a-btn selected" } else { "a-btn" };
+                        html! {
+                            <a {class} onclick={move |_: MouseEvent| report_reason.set(reason)}>{t!(reason.label_key())}</a>
+                        }
+                    }).collect::<Html>()
+                }
+                </div>
+                <textarea
+                    class={format!("{} comment-form-textarea", &theme)}
+                    value={(*draft).clone()}
+                    oninput={draft_oninput}
+                    placeholder={t!("report.details_hint")}
+                />
+                <div class="comment-actions">
+                    <button type="submit" class={format!("a-btn ripple {}", &theme)}>{t!("report.submit")}</button>
+                    <button type="button" class={format!("a-btn ripple {}", &theme)} onclick={cancel}>
+                        {t!("comments.cancel")}
+                    </button>
+                </div>
+            </form>
+        },
+        current => html! {
+            <form class="comment-inline-form" onsubmit={submit}>
+                <textarea
+                    class={format!("{} comment-form-textarea", &theme)}
+                    value={(*draft).clone()}
+                    oninput={draft_oninput}
+                    placeholder={t!("comments.markdown_hint")}
+                />
+                <div class="comment-actions">
+                    <button type="submit" class={format!("a-btn ripple {}", &theme)}>
+                        {if current == CommentMode::Editing { t!("comments.save") } else { t!("comments.reply") }}
+                    </button>
+                    <button type="button" class={format!("a-btn ripple {}", &theme)} onclick={cancel}>
+                        {t!("comments.cancel")}
+                    </button>
+                </div>
+            </form>
+        },
+    };
+
+    let replies_class = if props.depth + 1 < MAX_REPLY_DEPTH {
+        "comment-replies nested"
+    } else {
+        "comment-replies"
+    };
+
+    html! {
+        <div class="comment-thread">
+            <div class={format!("comment-card obj-level-1 {} {}", &theme, if comment.hidden { "mod-hidden" } else { "" })}>
+                if comment.deleted {
+                    <span class="username">{t!("comments.deleted_user")}</span>
+                    <span class="date">{&comment.date}</span>
+                    <span class="comment deleted">{t!("comments.deleted")}</span>
+                } else if block_list.contains(&comment.username) {
+                    <span class="comment deleted">{t!("comments.blocked", name = &comment.username)}</span>
+                    <div class="comment-actions">
+                        <a class="a-btn" onclick={toggle_block}>{t!("comments.unblock")}</a>
+                    </div>
+                } else {
+                    if comment.hidden {
+                        <span class="mod-hidden-label">{t!("moderation.hidden_label")}</span>
+                    }
+                    <span class="username">
+                        <a class="username-link cursor-pointer" onclick={open_profile}>{&comment.username}</a>
+                        if let (Some(n), 0) = (comment.episode, props.depth) {
+                            <span class="episode-tag">{t!("comments.episode_tag", n = n)}</span>
+                        }
+                    </span>
+                    <span class="date">
+                        {&comment.date}
+                        if let Some(edited_at) = &comment.edited_at {
+                            <span class="edited" title={edited_at.clone()}>{t!("comments.edited")}</span>
+                        }
+                    </span>
+                    <span class="comment">{render_markdown(&comment.comment)}</span>
+                    <div class="comment-actions">
+                        <a class={reaction_class(Reaction::Like)} title={t!("comments.like")} onclick={react(Reaction::Like)}>
+                            {format!("▲ {}", format_number(comment.likes as f64, 0))}
+                        </a>
+                        <a class={reaction_class(Reaction::Dislike)} title={t!("comments.dislike")} onclick={react(Reaction::Dislike)}>
+                            {format!("▼ {}", format_number(comment.dislikes as f64, 0))}
+                        </a>
+                        if cx.jwt.is_some() {
+                            <a class="a-btn" onclick={open(CommentMode::Replying)}>{t!("comments.reply")}</a>
+                        }
+                        if is_author {
+                            <a class="a-btn" onclick={open(CommentMode::Editing)}>{t!("comments.edit")}</a>
+                            <a class="a-btn" onclick={delete}>{t!("comments.delete")}</a>
+                        } else {
+                            <a class="a-btn" onclick={report}>{t!("report.action")}</a>
+                            <a class="a-btn" onclick={toggle_block.clone()}>{t!("comments.block")}</a>
+                        }
+                        if is_moderator && !is_author {
+                            <a class="a-btn" onclick={toggle_hidden}>
+                                {if comment.hidden { t!("moderation.unhide") } else { t!("moderation.hide") }}
+                            </a>
+                            <a class="a-btn danger" onclick={remove}>{t!("moderation.remove")}</a>
+                        }
+                    </div>
+                }
+                {form}
+            </div>
+            if !props.thread.replies.is_empty() {
+                <div class={replies_class}>
+                {
+                    props.thread.replies.iter().map(|reply| {
+                        html! {
+                            <CommentItem
+                                key={reply.comment.id}
+                                thread={reply.clone()}
+                                anime_id={props.anime_id}
+                                depth={props.depth + 1}
+                                on_changed={props.on_changed.clone()}
+                                on_updated={props.on_updated.clone()}
+                            />
+                        }
+                    }).collect::<Html>()
+                }
+                </div>
+            }
+        </div>
+    }
+}
+
+/// Anime, sort and episode a list of comments was loaded for.
+type CommentListKey = (i32, CommentSort, Option<u32>);
+
+/// Pages of comments loaded so far.
+#[derive(Clone, PartialEq, Default)]
+struct LoadedComments {
+    key: Option<CommentListKey>,
+    comments: Vec<AnimeCommentGet>,
+    next_cursor: Option<String>,
+    total: u32,
+}
+
+enum CommentsAction {
+    /// First page of a list, replacing whatever was loaded before.
+    Replace(CommentListKey, CommentPage),
+    /// Next page of the list currently loaded.
+    Append(CommentListKey, CommentPage),
+    /// One comment changed, e.g. its reactions.
+    Update(AnimeCommentGet),
+}
+
+impl Reducible for LoadedComments {
+    type Action = CommentsAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            CommentsAction::Replace(key, page) => LoadedComments {
+                key: Some(key),
+                comments: page.comments,
+                next_cursor: page.next_cursor,
+                total: page.total,
+            }
+            .into(),
+            // A page of a list that has since been replaced
+            CommentsAction::Append(key, _) if self.key != Some(key) => self,
+            CommentsAction::Append(_, page) => LoadedComments {
+                key: self.key,
+                comments: append_comment_page(&self.comments, page.comments),
+                next_cursor: page.next_cursor,
+                total: page.total,
+            }
+            .into(),
+            CommentsAction::Update(updated) => LoadedComments {
+                comments: self
+                    .comments
+                    .iter()
+                    .map(|c| if c.id == updated.id { updated.clone() } else { c.clone() })
+                    .collect(),
+                ..(*self).clone()
+            }
+            .into(),
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ContentProps {
+    anime_id: i32,
+    comments: Vec<AnimeCommentGet>,
+    on_changed: Callback<()>,
+    on_updated: Callback<AnimeCommentGet>,
+}
+
+#[function_component]
+fn Content(props: &ContentProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let anime_id = props.anime_id;
+    let (scope, scope_dispatch) = use_store::<CommentScope>();
 
     let comment_field = use_state(|| "".to_string());
 
@@ -35,44 +513,32 @@ fn Content(props: &CommentProps) -> HtmlResult {
     }));
 
     let submit_post = {
-        let comment_status = comment_status.clone();
         let comment_field = comment_field.clone();
         let cx = cx.clone();
         let theme = theme.clone();
-        let nav = nav.clone();
+        let on_changed = props.on_changed.clone();
+        let scope_dispatch = scope_dispatch.clone();
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
             let cx = cx.clone();
             let theme = theme.clone();
-            let nav = nav.clone();
+            let on_changed = on_changed.clone();
             let comment_field = comment_field.clone();
+            let episode = scope_dispatch.get().episode;
             wasm_bindgen_futures::spawn_local(async move {
                 let theme = theme.clone();
-                let auth = format!("Bearer {}", cx.jwt.as_ref().unwrap());
-                let url = format!("{}/api/v1/anime/{}/comment", BASE_URL, anime_id);
                 let comment = AnimeCommentPost {
                     comment: (*comment_field).clone(),
+                    parent_id: None,
+                    episode,
                 };
 
-                let result = reqwasm::http::Request::post(&url)
-                    .header("content-type", "application/json")
-                    .header("authorization", &auth)
-                    .body(serde_json::to_string(&comment).unwrap())
-                    .send()
-                    .await;
-                
-                match result {
-                    Ok(r) => {
-                        if r.status() == 201 || r.status() == 200 {
-                            alert("Your comment has been posted successfully.");
-                        } else {
-                            alert("Failed to post the commen    t. Please try to post your comment agian and relogin if the issue persists.");
-                        }
-                    },
-                    Err(_) => {
-                        alert("Failed to post the comment. Plese try to post your comment again.")
-                    }
+                // The dialog stays open on failure so the text is not lost
+                if let Err(e) = post_comment(&cx, anime_id, &comment).await {
+                    alert(&e.message());
+                    return;
                 }
+                alert(&t!("comments.posted"));
 
                 let elem = web_sys::window()
                     .unwrap()
@@ -84,10 +550,8 @@ fn Content(props: &CommentProps) -> HtmlResult {
                     .unwrap();
                 let dialog = elem.dyn_into::<HtmlDialogElement>().unwrap();
                 dialog.close();
-                nav.back();
-                nav.forward();
+                on_changed.emit(());
             });
-            comment_status.set(random());
         })
     };
 
@@ -99,74 +563,86 @@ fn Content(props: &CommentProps) -> HtmlResult {
         })
     };
 
+    let show_unwatched = {
+        let scope_dispatch = scope_dispatch.clone();
+        Callback::from(move |_: MouseEvent| scope_dispatch.reduce_mut(|s| s.show_unwatched = true))
+    };
+
     let theme_cloned = theme.clone();
-    let html_result = match &(*result) {
-        Err(_) => html!({ "Uncatched error." }),
-        Ok(comments) => {
-            html! {
-                    <>
-                    <dialog id="add-comment-popup" class={format!("add-comment {}", theme.clone())}>
-                        <div class="dialog-close-button-container">
-                            <h2 class="content-ttl">{"Add a Comment"}</h2>
-                            <a class="a-btn dialog-close-button ripple" onclick={move |_| {
-                                let elem = web_sys::window().unwrap().document().unwrap().get_element_by_id(&format!("add-comment-popup")).unwrap();
-                                elem.set_attribute("class", &format!("add-comment {}", theme_cloned.clone())).unwrap();
-                                let dialog = elem.dyn_into::<HtmlDialogElement>().unwrap();
-                                dialog.close();
-                                log!("closing dialog.");
-                            }}><img class={format!("icon-{}", theme.clone())}src="./static/dialog_close.png" width="24px" height="24px"/>
-                            </a>
-                        </div>
-
-                        <form id="post-comment" class="comment-form" onsubmit={submit_post}>
-                            <textarea class={format!("{} comment-form-textarea", &theme)} row={"100%"} oninput={comment_oninput}/>
-                            <input id="post-button" type="submit" class="hidden"/>
-                        </form>
-
-                        <div class={format!("full-width-btn cursor-pointer hover-highlight obj-level-1 {}", &theme)}>
-                            <label for ="post-button">
-                            <a class="a-btn" type="submit" form="post-comment" onclick={&*onsubmit_comment}>{"Post"}</a>
-                            </label>
-                        </div>
-                    </dialog>
+    let (threads, hidden) = scope_threads(build_threads(&props.comments), &scope);
+    let dialog_title = match scope.episode {
+        Some(n) => t!("comments.add_for_episode", n = n),
+        None => t!("comments.add"),
+    };
+    html! {
+        <>
+        <dialog id="add-comment-popup" class={format!("add-comment {}", theme.clone())}>
+            <div class="dialog-close-button-container">
+                <h2 class="content-ttl">{dialog_title}</h2>
+                <a class="a-btn dialog-close-button ripple" onclick={move |_| {
+                    let elem = web_sys::window().unwrap().document().unwrap().get_element_by_id(&format!("add-comment-popup")).unwrap();
+                    elem.set_attribute("class", &format!("add-comment {}", theme_cloned.clone())).unwrap();
+                    let dialog = elem.dyn_into::<HtmlDialogElement>().unwrap();
+                    dialog.close();
+                    log!("closing dialog.");
+                }}><img class={format!("icon-{}", theme.clone())}src="./static/dialog_close.png" width="24px" height="24px"/>
+                </a>
+            </div>
+
+            <form id="post-comment" class="comment-form" onsubmit={submit_post}>
+                <textarea class={format!("{} comment-form-textarea", &theme)} row={"100%"} oninput={comment_oninput} placeholder={t!("comments.markdown_hint")}/>
+                <input id="post-button" type="submit" class="hidden"/>
+            </form>
+
+            <div class={format!("full-width-btn cursor-pointer hover-highlight obj-level-1 {}", &theme)}>
+                <label for ="post-button">
+                <a class="a-btn" type="submit" form="post-comment" onclick={&*onsubmit_comment}>{t!("comments.post")}</a>
+                </label>
+            </div>
+        </dialog>
+        if hidden > 0 {
+            <div class="comment-scope">
+                <span>{t!("comments.hidden_unwatched", count = hidden)}</span>
+                <a class="a-btn" onclick={show_unwatched}>{t!("comments.show_anyway")}</a>
+            </div>
+        }
+        {
+            if threads.is_empty() && hidden == 0 {
+                html! {
+                    <span class="no-result">{
+                        match scope.episode {
+                            Some(n) => t!("comments.episode_empty", n = n),
+                            None => t!("comments.empty"),
+                        }
+                    }</span>
+                }
+            } else if threads.is_empty() {
+                html! {}
+            } else {
+                html!{
+                    <div class="comments-wrapper">
                     {
-                        if comments.len() == 0 {
-                            html! {
-                                <span class="no-result">{"There are no comments for this anime yet."}</span>
-                            }
-                        } else {
+                        threads.into_iter().map(|thread| {
+                            let key = thread.comment.id;
                             html!{
-                                <div class="comments-wrapper">
-                                // <p style="display: hidden;">{*comment_status}</p>
-                                {
-                                    comments.into_iter().map(|c| {
-                                        html!{  <>
-                                                // <p>{*comment_status.clone()}</p>
-                                                <div class={format!("comment-card obj-level-1 {}", &theme)}>
-                                                    <span class="username">{&c.username}</span>
-                                                    <span class="date">{&c.date}</span>
-                                                    <span class="comment">{&c.comment}</span>
-                                                </div>
-                                                </>
-                                        }
-                                    }).collect::<Html>()
-                                }
-                                </div>
+                                <CommentItem
+                                    key={key}
+                                    {thread}
+                                    {anime_id}
+                                    depth={0}
+                                    on_changed={props.on_changed.clone()}
+                                    on_updated={props.on_updated.clone()}
+                                />
                             }
-
-                        }
+                        }).collect::<Html>()
                     }
-            </>
+                    </div>
+                }
+
             }
         }
-    };
-
-    Ok(html! 
-        {
-            <>
-            {html_result}
-            </>
-        })
+        </>
+    }
 }
 
 #[function_component]
@@ -174,18 +650,113 @@ pub fn CommentSection(props: &CommentProps) -> Html {
     let cx = use_context::<AppContext>().unwrap();
     let theme = handle_theme(&cx);
     let anime_id = props.anime_id;
-    let nav = use_navigator().unwrap();
+    let (scope, scope_dispatch) = use_store::<CommentScope>();
+    let sort = use_state(CommentSort::default);
+    let loaded = use_reducer(LoadedComments::default);
+    let loading = use_state(|| false);
+    let failed = use_state(|| false);
+    // Bumped to reload the list in place, e.g. after posting
+    let refresh = use_state(|| 0u32);
+    // Id of the newest first-page request, so an older response cannot overwrite it
+    let latest_request = use_mut_ref(|| 0u32);
+    let key: CommentListKey = (anime_id, *sort, scope.episode);
+
+    // Episode scope and progress belong to one anime
+    {
+        let scope_dispatch = scope_dispatch.clone();
+        use_effect_with(anime_id, move |anime_id| {
+            if scope_dispatch.get().mal_id != *anime_id as u32 {
+                scope_dispatch.set(CommentScope::for_anime(*anime_id as u32));
+            }
+        });
+    }
+
+    {
+        let cx = cx.clone();
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+        let failed = failed.clone();
+        let latest_request = latest_request.clone();
+        use_effect_with(
+            (key, *refresh, cx.jwt.is_some()),
+            move |((anime_id, sort, episode), _, _)| {
+                let key = (*anime_id, *sort, *episode);
+                let request = {
+                    let mut latest = latest_request.borrow_mut();
+                    *latest += 1;
+                    *latest
+                };
+                loading.set(true);
+                failed.set(false);
+                wasm_bindgen_futures::spawn_local(async move {
+                    let result = fetch_comment_page(&cx, key.0, key.1, key.2, None).await;
+                    if *latest_request.borrow() != request {
+                        return;
+                    }
+                    match result {
+                        Ok(page) => loaded.dispatch(CommentsAction::Replace(key, page)),
+                        Err(e) => {
+                            log!(format!("{:?}", e));
+                            failed.set(true);
+                        }
+                    }
+                    loading.set(false);
+                });
+            },
+        );
+    }
+
+    let on_changed = {
+        let refresh = refresh.clone();
+        Callback::from(move |_: ()| refresh.set(*refresh + 1))
+    };
+
+    let on_updated = {
+        let loaded = loaded.clone();
+        Callback::from(move |c: AnimeCommentGet| loaded.dispatch(CommentsAction::Update(c)))
+    };
 
-    let trigger = use_force_update();
-    let onrefresh = use_state(move || Callback::from(move |_:MouseEvent| trigger.force_update()));
+    let load_more = {
+        let cx = cx.clone();
+        let loaded = loaded.clone();
+        let loading = loading.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (Some(key), Some(cursor)) = (loaded.key, loaded.next_cursor.clone()) else {
+                return;
+            };
+            let cx = cx.clone();
+            let loaded = loaded.clone();
+            let loading = loading.clone();
+            loading.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                match fetch_comment_page(&cx, key.0, key.1, key.2, Some(cursor)).await {
+                    Ok(page) => loaded.dispatch(CommentsAction::Append(key, page)),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        alert(&t!("comments.load_failed"));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    let retry = {
+        let on_changed = on_changed.clone();
+        Callback::from(move |_: MouseEvent| on_changed.emit(()))
+    };
+
+    let show_all_episodes = {
+        let scope_dispatch = scope_dispatch.clone();
+        Callback::from(move |_: MouseEvent| scope_dispatch.reduce_mut(|s| s.episode = None))
+    };
 
     let add_comment = {
         let cx = cx.clone();
         let theme = theme.clone();
         if let None = cx.jwt.as_ref() {
             Callback::from(|_: MouseEvent| {
-                alert("You need to be logged in to post a comment. \
-                \nOpen the left navigation bar (by clicking on the three stripes icon) to log in or create an account.");
+                alert(&t!("comments.login_required"));
             })
         } else {
             Callback::from(move |_: MouseEvent| {
@@ -205,22 +776,76 @@ pub fn CommentSection(props: &CommentProps) -> Html {
         }
     };
 
+    let is_current = loaded.key == Some(key);
+
     html! {
-        <section class="ad-comment-wrapper">
+        <section id="comment-section" class="ad-comment-wrapper">
             <div class="ad-section-header">
                 <div class="ad-section-header-left">
-                    <img loading="lazy" id="comment-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/comment.svg"/>
-                    <h2 id="ad-section-header" class="content-ttl">{"Comments"}</h2>
+                    <span class="comment-icon-wrapper" title={t!("comments.count", count = loaded.total)}>
+                        <img loading="lazy" id="comment-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/comment.svg"/>
+                        if is_current && loaded.total > 0 {
+                            <span class="count-badge">{format_count_badge(loaded.total)}</span>
+                        }
+                    </span>
+                    <h2 id="ad-section-header" class="content-ttl">{t!("comments.title")}</h2>
                 </div>
                 <div class="ad-section-header-right">
+                    <div class="comment-sort">
+                    {
+                        CommentSort::ALL.iter().map(|option| {
+                            let option = *option;
+                            let sort = sort.clone();
+                            let class = if *sort == option { "a-btn selected" } else { "a-btn" };
+                            html! {
+                                <a {class} onclick={move |_: MouseEvent| sort.set(option)}>{t!(option.label_key())}</a>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </div>
                     <a class="a-btn ripple" onclick={add_comment}>
                         <img id="plus-icon" src="./static/plus.svg" height="36px" class={format!("icon-{}", &theme)}/>
                     </a>
                 </div>
             </div>
-            <Suspense fallback={html!{<Loading/>}}>
-                <Content anime_id={props.anime_id}/>
-            </Suspense>
+            if scope.episode.is_some() || scope.progress.is_some() {
+                <div class="comment-scope">
+                    if let Some(n) = scope.episode {
+                        <span>{t!("comments.scoped_to_episode", n = n)}</span>
+                        <a class="a-btn" onclick={show_all_episodes}>{t!("comments.all_episodes")}</a>
+                    }
+                    if let Some(p) = scope.progress {
+                        <span>{t!("comments.watched_up_to", n = p)}</span>
+                    }
+                </div>
+            }
+            {
+                if !is_current && *failed {
+                    html! {
+                        <a class="a-btn u_onhover" onclick={retry}>{t!("comments.load_failed_retry")}</a>
+                    }
+                } else if !is_current {
+                    html! {<Loading/>}
+                } else {
+                    html! {
+                        <>
+                        <Content
+                            {anime_id}
+                            comments={loaded.comments.clone()}
+                            {on_changed}
+                            {on_updated}
+                        />
+                        if *loading {
+                            <Loading/>
+                        } else if loaded.next_cursor.is_some() {
+                            <div class={format!("full-width-btn cursor-pointer hover-highlight obj-level-1 {}", &theme)}>
+                                <a class="a-btn" onclick={load_more}>{t!("comments.load_more")}</a>
+                            </div>
+                        }
+                        </>
+                    }
+                }
+            }
         </section>
     }
 }
@@ -0,0 +1,225 @@
+use crate::prelude::*;
+
+/// Ids of the tags that every compared anime has.
+fn shared_tags(lists: &[&Vec<MALObj>]) -> Vec<u32> {
+    match lists.split_first() {
+        None => vec![],
+        Some((first, rest)) => first
+            .iter()
+            .map(|t| t.mal_id)
+            .filter(|id| rest.iter().all(|l| l.iter().any(|t| t.mal_id == *id)))
+            .collect(),
+    }
+}
+
+fn tags_row(label: &str, lists: Vec<&Vec<MALObj>>, theme: &str) -> Html {
+    // Only worth highlighting when there is something to compare against
+    let shared = if lists.len() > 1 {
+        shared_tags(&lists)
+    } else {
+        vec![]
+    };
+
+    html! {
+        <tr>
+            <th class="compare-label">{label}</th>
+            {
+                lists.iter().map(|tags| html! {
+                    <td>
+                    {
+                        if tags.is_empty() {
+                            html!{<span class="compare-none">{"-"}</span>}
+                        } else {
+                            tags.iter().map(|t| {
+                                let kind = if shared.contains(&t.mal_id) { "compare-shared" } else { "compare-diff" };
+                                html!{<span class={format!("compare-tag {} {}", kind, theme)}>{t.name.clone()}</span>}
+                            }).collect::<Html>()
+                        }
+                    }
+                    </td>
+                }).collect::<Html>()
+            }
+        </tr>
+    }
+}
+
+/// A row of plain values. `best` picks the index of the column to highlight, if any.
+fn value_row(label: &str, values: Vec<Option<String>>, best: Option<usize>) -> Html {
+    let differs = values.windows(2).any(|w| w[0] != w[1]);
+
+    html! {
+        <tr class={if differs { "compare-row-diff" } else { "" }}>
+            <th class="compare-label">{label}</th>
+            {
+                values.into_iter().enumerate().map(|(i, v)| html! {
+                    <td class={if best == Some(i) { "compare-best" } else { "" }}>
+                        {v.unwrap_or("-".to_string())}
+                    </td>
+                }).collect::<Html>()
+            }
+        </tr>
+    }
+}
+
+fn best_by<T: PartialOrd + Copy>(values: &[Option<T>], higher_is_better: bool) -> Option<usize> {
+    let mut best: Option<(usize, T)> = None;
+    for (i, v) in values.iter().enumerate() {
+        if let Some(v) = *v {
+            best = match best {
+                Some((_, b)) if (higher_is_better && b >= v) || (!higher_is_better && b <= v) => best,
+                _ => Some((i, v)),
+            };
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct CompareTableProp {
+    ids: Vec<u64>,
+}
+
+#[function_component(CompareTable)]
+fn compare_table(props: &CompareTableProp) -> HtmlResult {
+    let cx = use_context::<AppContext>().unwrap();
+    let nav = use_navigator().unwrap();
+    let theme = handle_theme(&cx);
+
+    let result: UseFutureHandle<Result<Vec<AnimeObjFull>, gloo::net::Error>> =
+        use_future_with(props.ids.clone(), |ids| async move {
+            let mut v = Vec::new();
+            // One at a time so that Jikan's rate limit is not hit
+            for id in ids.iter() {
+                let link = format!("https://api.jikan.moe/v4/anime/{}/full", id);
+                v.push(force_req::<AnimeObjFullAsQueryResult>(&link).await?.data);
+            }
+            Ok(v)
+        })?;
+
+    let html_result = match (*result).as_ref() {
+        Ok(v) => {
+            let scores = v
+                .iter()
+                .map(|ao| ao.score.as_ref().and_then(|s| s.parse::<f64>().ok()))
+                .collect::<Vec<Option<f64>>>();
+            let ranks = v.iter().map(|ao| ao.rank).collect::<Vec<Option<u32>>>();
+            let popularity = v.iter().map(|ao| ao.popularity).collect::<Vec<Option<u32>>>();
+
+            html! {
+                <div class="compare-table-wrapper">
+                <table class={format!("compare-table {}", theme)}>
+                    <thead>
+                        <tr>
+                            <th></th>
+                            {
+                                v.iter().map(|ao| {
+                                    let mal_id = ao.mal_id;
+                                    let nav = nav.clone();
+                                    let title = display_title(&cx, &AnimeObj::from(ao.clone()));
+                                    html! {
+                                        <th class="compare-head cursor-pointer" onclick={move |_| nav.push(&Route::AnimeDetails { mal_id })}>
+                                            <img class="compare-cover" loading="lazy" src={ao.images.clone().webp.image_url.unwrap_or(ao.images.clone().jpg.image_url.unwrap_or_default())}/>
+                                            <span class="u_onhover">{title}</span>
+                                        </th>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {value_row(&t!("compare.score"), scores.iter().map(|s| s.map(|s| format!("{:.2}", s))).collect(), best_by(&scores, true))}
+                        {value_row(&t!("compare.rank"), ranks.iter().map(|r| r.map(|r| format!("#{}", r))).collect(), best_by(&ranks, false))}
+                        {value_row(&t!("compare.popularity"), popularity.iter().map(|p| p.map(|p| format!("#{}", p))).collect(), best_by(&popularity, false))}
+                        {value_row(&t!("compare.episodes"), v.iter().map(|ao| ao.episodes.map(|e| e.to_string())).collect(), None)}
+                        {value_row(&t!("compare.season"), v.iter().map(|ao| match (ao.season.as_ref(), ao.year) {
+                            (Some(s), Some(y)) => Some(format!("{} {}", capitalize(s), y)),
+                            (None, Some(y)) => Some(y.to_string()),
+                            _ => None,
+                        }).collect(), None)}
+                        {tags_row(&t!("compare.studios"), v.iter().map(|ao| &ao.studios).collect(), &theme)}
+                        {tags_row(&t!("compare.genres"), v.iter().map(|ao| &ao.genres).collect(), &theme)}
+                        {tags_row(&t!("compare.themes"), v.iter().map(|ao| &ao.themes).collect(), &theme)}
+                    </tbody>
+                </table>
+                </div>
+            }
+        }
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {<ErrorPage app_err={AppErr::gloo_default()}/>}
+        }
+    };
+
+    Ok(html_result)
+}
+
+fn capitalize(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        Some(f) => f.to_uppercase().chain(c).collect(),
+        None => String::new(),
+    }
+}
+
+#[function_component(CompareAnime)]
+pub fn compare_anime() -> Html {
+    let location = yew_router::prelude::use_location().unwrap();
+    let ids = location
+        .query::<CompareQuery>()
+        .map(|q| q.mal_ids())
+        .unwrap_or_default();
+    use_title(format!("{} | ANiNFO", t!("compare.page_title")));
+
+    html! {
+        <div class="search-result-wrapper">
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("compare.page_title")}</h2></span>
+            </div>
+            {
+                if ids.len() < 2 {
+                    html!{
+                        <p style="text-align: center !important;">{t!("compare.pick_two")}</p>
+                    }
+                } else {
+                    html!{
+                        <Suspense fallback={html!(<Loading/>)}>
+                            <CompareTable {ids}/>
+                        </Suspense>
+                    }
+                }
+            }
+        </div>
+    }
+}
+
+/// Floating bar listing the current picks, shown once something is added to compare.
+#[function_component(CompareTray)]
+pub fn compare_tray() -> Html {
+    let (list, dispatch) = use_store::<CompareList>();
+    let nav = use_navigator().unwrap();
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+
+    if list.ids.is_empty() {
+        return html! {};
+    }
+
+    let go_to_compare = {
+        let list = list.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Err(e) = nav.push_with_query(&Route::Compare, &list.to_query()) {
+                log!(format!("{:?}", e));
+            }
+        })
+    };
+
+    let clear = Callback::from(move |_: MouseEvent| dispatch.set(CompareList::default()));
+
+    html! {
+        <div class={format!("compare-tray obj-level-2 {}", theme)}>
+            <span>{t!("compare.picked", count = list.ids.len(), max = MAX_COMPARE)}</span>
+            <a class={format!("card-btn hover-highlight cursor-pointer {}", theme)} onclick={go_to_compare}>{t!("compare.open")}</a>
+            <a class="a-btn u_onhover cursor-pointer" onclick={clear}>{t!("compare.clear")}</a>
+        </div>
+    }
+}
@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::ops::Deref;
+use std::rc::Rc;
 
 use crate::prelude::*;
 use gloo::dialogs::alert;
@@ -17,9 +19,8 @@ pub const FILTER_TYPES: [Filter; 6] = [
 pub struct EpisodeCardProps {
     pub anime_ttl_def: String,
     pub anime_ttl_en: String,
-    pub eo: Vec<AnimeEpisode>,
-    pub eps_total: usize,
-    pub total_pages: usize,
+    /// Episode count from the anime's details, `None` when Jikan does not list one.
+    pub listed_episodes: Option<usize>,
     pub mal_id: u64,
 }
 
@@ -30,10 +31,13 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
     let theme_cheap = use_state(|| theme.clone());
     let ttl_def = props.anime_ttl_def.clone();
     let ttl_en = props.anime_ttl_en.clone();
-    let eps_total = props.eps_total;
+    let listed = props.listed_episodes;
     let mal_id = props.mal_id;
     let get_torrent_full = use_state(|| false);
     let show_episode_titles = use_state(|| false);
+    let hide_filler = use_state(|| false);
+    let nav = use_navigator().unwrap();
+    let (comment_scope, scope_dispatch) = use_store::<CommentScope>();
 
     let client_query = use_state(|| String::new());
     let update_client_query = {
@@ -48,91 +52,61 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
     let ttl_cheap_en = use_state(|| props.anime_ttl_en.clone());
 
     let filters: UseStateHandle<Vec<Filter>> = use_state(|| vec![]);
-    let cur_page = use_state(|| 1usize);
-    let eps_start = use_state(|| 1usize);
-    let eps_end = use_state(|| 1usize);
-    let torrent_data_state: UseStateHandle<Vec<Option<Vec<Torrent>>>> = use_state(|| {
-        (0..eps_total)
-            .into_iter()
-            .map(|_| None)
-            .collect::<Vec<Option<Vec<Torrent>>>>()
-    });
-    let eo_full = use_state(|| {
-        let mut eo_full = props
-            .eo
-            .clone()
-            .into_iter()
-            .map(|eo| Some(eo))
-            .collect::<Vec<Option<AnimeEpisode>>>();
-        while eo_full.len() < (eps_total) {
-            eo_full.push(None);
-        }
-        eo_full
-    }); 
-
-    if eps_total == 0 {
-        return html!(
-            <div class={format!("episodes-wrapper {}", theme.clone())}>
-            <div class="ad-section-header">
-            <img id="episodes-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/episode.png"/><h2 id="ad-section-header" class="content-ttl">{"Episodes"}</h2></div>
-            <span class="no-result">{"Episodes data is unavailable."}</span>
-            </div>
-        );
-    }
+    let cur_page = use_state(|| 1u32);
+    let torrent_data_state: UseStateHandle<HashMap<usize, Vec<Torrent>>> = use_state(HashMap::new);
+    let (episodes, episodes_dispatch) = use_store::<EpisodeStore>();
+    // Pages left over from the previous anime are not shown while this one loads
+    let episodes = if episodes.mal_id == mal_id {
+        episodes
+    } else {
+        Rc::new(EpisodeStore::for_anime(mal_id))
+    };
 
-    // Use effect to update when anime is changed
+    // Reset when another anime is opened
     {
         let cur_page = cur_page.clone();
-        let eps_start = eps_start.clone();
-        let eps_end = eps_end.clone();
-        let eo_full = eo_full.clone();
-        let eps_total = props.eps_total;
-        let eo = props.eo.clone();
         let torrent_data_state = torrent_data_state.clone();
+        use_effect_with(mal_id, move |_| {
+            cur_page.set(1);
+            torrent_data_state.set(HashMap::new());
+        });
+    }
 
-        use_effect_with(props.clone(), move |_| {
-            // log!("resetting values...");
-            cur_page.set(1usize);
-            eps_start.set(1usize);
-            if eps_total <= 100 {
-                eps_end.set(eps_total);
-            } else {
-                eps_end.set(100);
+    {
+        let episodes_dispatch = episodes_dispatch.clone();
+        use_effect_with((mal_id, *cur_page), move |(mal_id, page)| {
+            // The range of the previous anime, about to be reset to the first one
+            if *page == 1 || episodes_dispatch.get().mal_id == *mal_id {
+                load_episode_page(episodes_dispatch, *mal_id, *page);
             }
+        });
+    }
 
-            torrent_data_state.set(
-                (0..eps_total)
-                    .into_iter()
-                    .map(|_| None)
-                    .collect::<Vec<Option<Vec<Torrent>>>>(),
-            );
-
-            eo_full.set({
-                let mut eo_full = eo
-                    .clone()
-                    .into_iter()
-                    .map(|eo| Some(eo))
-                    .collect::<Vec<Option<AnimeEpisode>>>();
-                // log!("eo_full length: ", eo_full.len());
-                // while eo_full.len() < eps_total {
-                // eo_full.push(None);
-                // log!("updating eo_full length to", eo_full.len());
-                // }
-                if eo_full.len() < eps_total {
-                    eo_full.resize(eps_total, None);
-                    // log!("updating eo_full length to", eo_full.len());
-                }
-
-                eo_full
-            })
+    let page_count = episodes.page_count(listed);
+    let range = episodes.page_range(*cur_page, listed);
+    let no_episodes = listed == Some(0) || episodes.total(listed) == Some(0);
+
+    // Fetches the next range ahead of time once the list is scrolled near its end
+    let prefetch_next = {
+        let episodes_dispatch = episodes_dispatch.clone();
+        let next = *cur_page + 1;
+        Callback::from(move |e: Event| {
+            let list = e.target_unchecked_into::<web_sys::Element>();
+            let remaining = list.scroll_height() - list.scroll_top() - list.client_height();
+            if next <= page_count && remaining < list.client_height() {
+                load_episode_page(episodes_dispatch.clone(), mal_id, next);
+            }
         })
-    }
+    };
 
-    {
-        let torrent_data_state = torrent_data_state.clone();
-        use_effect_with(torrent_data_state, |_| {
-            // log!("state changed.");
-        });
+    if no_episodes {
+        return html!(
+            <div class={format!("episodes-wrapper {}", theme.clone())}>
+            <div class="ad-section-header">
+            <img id="episodes-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/episode.png"/><h2 id="ad-section-header" class="content-ttl">{t!("episodes.title")}</h2></div>
+            <span class="no-result">{t!("episodes.unavailable")}</span>
+            </div>
+        );
     }
 
     let show_episode_titles_cb = {
@@ -145,7 +119,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                 checkbox.set_checked(false);
                 let episodes = document.get_elements_by_class_name("eps-title");
                 (0..episodes.length()).for_each(|i| {
-                    episodes.get_with_index(i).unwrap().set_attribute("class", "eps-title cursor-arrow normally-blurred");
+                    episodes.get_with_index(i).unwrap().set_attribute("class", "eps-title cursor-pointer normally-blurred");
                 });
             } else {
                 show_episode_titles.set(true);
@@ -153,12 +127,36 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                 checkbox.set_checked(true);
                 let episodes = document.get_elements_by_class_name("eps-title");
                 (0..episodes.length()).for_each(|i| {
-                    episodes.get_with_index(i).unwrap().set_attribute("class", "eps-title cursor-arrow");
+                    episodes.get_with_index(i).unwrap().set_attribute("class", "eps-title cursor-pointer");
                 });
             }
         })
     };
 
+    let toggle_hide_filler = {
+        let hide_filler = hide_filler.clone();
+        Callback::from(move |_: MouseEvent| hide_filler.set(!*hide_filler))
+    };
+
+    let range_eps = range.map_or(0..0, |(start, end)| start..end + 1);
+    let is_filler = |n: usize| episodes.episode(n).map_or(false, |eo| eo.filler);
+    let hidden_filler = if *hide_filler {
+        range_eps.clone().filter(|n| is_filler(*n)).count()
+    } else {
+        0
+    };
+    let range_scores = range_eps
+        .clone()
+        .map(|n| (n as u32, episodes.episode(n).and_then(|eo| eo.score)))
+        .collect::<Vec<(u32, Option<f32>)>>();
+    let page_loaded = episodes.has_page(*cur_page);
+    let retry_page = {
+        let episodes_dispatch = episodes_dispatch.clone();
+        let page = *cur_page;
+        Callback::from(move |_: MouseEvent| load_episode_page(episodes_dispatch.clone(), mal_id, page))
+    };
+    let page_failed = episodes.failed.contains(&*cur_page);
+
     // let bdrip_filter = {
     //     let ttl_cheap_def = ttl_cheap_def.clone();
     //     let ttl_cheap_en = ttl_cheap_en.clone();
@@ -221,22 +219,76 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
             <div class={format!("episodes-wrapper {}", theme.clone())}>
                 <div class="ad-section-header">
                     <img id="episodes-icon"  class={format!("ad-section-icon hideable icon-{}", theme.clone())} src="./static/episode.png"/>
-                    <h2 id="ad-section-header" class="content-ttl">{"Episodes"}</h2>
+                    <h2 id="ad-section-header" class="content-ttl">{t!("episodes.title")}</h2>
                 </div>
 
                 <div class="ad-section-top-options">
                     <input type="checkbox" id="show-eps-ttl-checkbox" onclick={show_episode_titles_cb}/>
                     <label for="show-eps-ttl-checkbox">{" Show ALL episode titles (you can hover onto the specific episode title to view it)"}</label>
                 </div>
+                <div class="ad-section-top-options">
+                    <input type="checkbox" id="hide-filler-checkbox" checked={*hide_filler} onclick={toggle_hide_filler}/>
+                    <label for="hide-filler-checkbox">{format!(" {}", t!("episodes.hide_filler"))}</label>
+                    if hidden_filler > 0 {
+                        <span class="eps-hidden-count">{t!("episodes.filler_hidden", count = hidden_filler)}</span>
+                    }
+                </div>
 
-                <div class="eps-wrapper">
+                if range_scores.iter().any(|(_, score)| score.is_some()) {
+                    <div class="eps-sparkline">
+                        <span class="eps-sparkline-label">{t!("episodes.scores")}</span>
+                        {episode_sparkline(&range_scores)}
+                    </div>
+                }
+
+                <div class="eps-wrapper" onscroll={prefetch_next}>
+                if page_failed {
+                    <span class="no-result">
+                        {t!("episodes.load_error")}{" "}
+                        <a class="a-btn u_onhover cursor-pointer" onclick={retry_page}>{t!("episodes.retry")}</a>
+                    </span>
+                } else if range.is_none() {
+                    <Loading/>
+                }
                 // Handle iterating all the available episodes and torrent fetching logic
                 {
-                    (*eps_start..*eps_end+1).into_iter().map(|n| {
+                    range_eps.clone().filter(|n| !(*hide_filler && is_filler(*n))).map(|n| {
 
                         let ttl_def = ttl_def.clone();
                         let ttl_en = ttl_en.clone();
                         let theme = theme.clone();
+                        let watched = comment_scope.progress.map_or(false, |p| n as u32 <= p);
+                        let episode = episodes.episode(n).cloned();
+
+                        let toggle_watched = {
+                            let scope_dispatch = scope_dispatch.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                scope_dispatch.reduce_mut(|scope| {
+                                    scope.progress = toggle_episode_progress(scope.progress, n as u32);
+                                });
+                                save_episode_progress(mal_id as u32, scope_dispatch.get().progress);
+                            })
+                        };
+
+                        let open_detail = {
+                            let nav = nav.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                nav.push(&Route::EpisodeDetail { mal_id, n: n as u32 });
+                            })
+                        };
+
+                        let discuss = {
+                            let scope_dispatch = scope_dispatch.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                scope_dispatch.reduce_mut(|scope| scope.episode = Some(n as u32));
+                                if let Some(section) = web_sys::window()
+                                    .and_then(|w| w.document())
+                                    .and_then(|d| d.get_element_by_id("comment-section"))
+                                {
+                                    section.scroll_into_view();
+                                }
+                            })
+                        };
 
                         // Inform users that episode data is unavailable/currently fetching
 
@@ -262,7 +314,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                 query.set("".to_string());
 
                                 {
-                                    if let None = (*torrent_data_state)[(n-1) as usize] {
+                                    if !(*torrent_data_state).contains_key(&n) {
                                         wasm_bindgen_futures::spawn_local(async move {
                                             let torrent_data_state = torrent_data_state.clone();
                                             let get_torrent_full = get_torrent_full.clone();
@@ -273,7 +325,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                             torrent_data_state.set({
                                                 log!(format!("eps {} data is updated.", n));
                                                 let mut v = (*torrent_data_state).clone();
-                                                v[(n-1) as usize] = Some(result);
+                                                v.insert(n, result);
                                                 v
                                             });
                                         });
@@ -295,27 +347,38 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                             });
 
                             html!{
-                            <div class={format!("eps-card hover-highlight {}", theme)}>
+                            <div class={format!("eps-card hover-highlight {} {}", theme, if watched { "watched" } else { "" })}>
                                 <div class="eps-header">
                                     <div class="eps-info">
-                                        <div class="eps-no">{n}</div>
+                                        <div class="eps-no cursor-pointer" title={t!("episodes.open_detail")} onclick={open_detail.clone()}>{n}</div>
+                                        if let Some(eo) = &episode {
+                                            if eo.filler {
+                                                <span class="eps-badge filler" title={t!("episodes.filler_hint")}>{t!("episodes.filler")}</span>
+                                            }
+                                            if eo.recap {
+                                                <span class="eps-badge recap" title={t!("episodes.recap_hint")}>{t!("episodes.recap")}</span>
+                                            }
+                                            if let Some(score) = eo.score {
+                                                <span class="eps-score" title={t!("episodes.score_tooltip", n = n, score = format_number(score as f64, 2))}>{format!("★ {}", format_number(score as f64, 2))}</span>
+                                            }
+                                        }
                                         {
-                                            if None == (*eo_full)[n-1] {
-                                                html!{
-                                                    <div class="eps-title">{"Episode data is currently unavailable."}</div>
-                                                }
-                                            } else if ((n-1) as usize) < (*eo_full).len() {
-                                                if let Some(ref url) = (*eo_full)[(n-1) as usize].as_ref().unwrap().url.clone() {
-                                                    html!{<span class="eps-title cursor-arrow normally-blurred"><a target="_blank" href={(*eo_full)[(n-1) as usize].as_ref().unwrap().url.as_ref().unwrap().to_string()}>{handle_eps_language(&cx, (*eo_full)[(n-1) as usize].as_ref().unwrap())}</a></span>}
-                                                } else {
-                                                    html!{<span class="eps-title cursor-arrow normally-blurred">{handle_eps_language(&cx, (*eo_full)[(n-1) as usize].as_ref().unwrap())}</span>}
-                                                }
-                                            } else {
-                                                html!{"Loading.."}
+                                            match &episode {
+                                                Some(eo) => html!{<span class="eps-title cursor-pointer normally-blurred" onclick={open_detail.clone()}>{display_episode_title(&cx, eo)}</span>},
+                                                None if page_loaded || page_failed => html!{
+                                                    <div class="eps-title">{t!("episodes.episode_unavailable")}</div>
+                                                },
+                                                None => html!{{t!("common.loading")}},
                                             }
                                         }
                                     </div>
                                     <div class="dropdown-icon">
+                                            <a class={format!("a-btn ripple cursor-pointer eps-watched {}", if watched { "active" } else { "" })}
+                                                title={if watched { t!("episodes.unmark_watched") } else { t!("episodes.mark_watched") }}
+                                                onclick={toggle_watched}>{"✓"}</a>
+                                            <a class="a-btn ripple cursor-pointer" title={t!("episodes.discuss")} onclick={discuss}>
+                                                <img class={format!("eps-dropdown icon-{}", handle_theme(&cx))} src="./static/comment.svg"/>
+                                            </a>
                                             <a class="a-btn ripple cursor-pointer" onclick={open_dwld}>
                                                 <img class={format!("eps-dropdown icon-{}", handle_theme(&cx))} src="./static/external.png"/>
                                             </a>
@@ -329,112 +392,27 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                 </div>
 
                 // Handle episode buttons
-                {
-                    if props.total_pages > 1 {
-                    html!{
-                        <div class="eps-page-btns-wrapper">
-                        {(1..props.total_pages+1).into_iter().map(|n| {
-                            let eps_start = eps_start.clone();
-                            let eps_end = eps_end.clone();
-                            let eps_total = eps_total.clone();
-                            let cur_page = cur_page.clone();
-                            let eo_full = eo_full.clone();
-                            let pages_total = props.total_pages;
-                            if n == (*cur_page) {
-                                html! {
-                                    <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected hover-highlight {}", theme)} onclick={move |_: MouseEvent| {
-                                        log!("btn pushed");
-                                        if n == pages_total {
-                                            eps_start.set((n-1)*100 + 1);
-                                            eps_end.set(eps_total);
-                                        } else {
-                                            eps_start.set((n-1)*100 + 1);
-                                            eps_end.set((n-1)*100 + 100);
-                                        }
-
-                                        let eo_full = eo_full.clone();
-                                        let cur_page = cur_page.clone();
-                                        {
-                                            let eo_full = eo_full.clone();
-                                            wasm_bindgen_futures::spawn_local(async move {
-                                                let link = format!("https://api.jikan.moe/v4/anime/21/episodes?page={}", n);
-                                                let result = force_req::<AnimeEpisodeWrapper>(&link).await.unwrap().data;
-                                                let mut cur_eo = (*eo_full).clone();
-                                                let mut i = n*100;
-                                                result.into_iter().for_each(|eo| {
-                                                    cur_eo[i] = Some(eo);
-                                                    i += 1;
-                                                });
-
-                                                eo_full.set(cur_eo);
-
-                                            });
-                                        }
-
-                                        cur_page.set(n);
-                                        log!("eps page: ", n);
-                                    }}>{format!("{} - {}", (n-1)*100+1,{
-                                        if n == pages_total {
-                                            eps_total
-                                        } else {
-                                            n*100
-                                        }
-                                    })}</button>
-                                }
-                            } else {
-                                html! {
-                                    <button class={format!("cursor-pointer page-btn hover-highlight {}", theme)} onclick={move |_: MouseEvent| {
-                                        log!("btn pushed");
-                                        if n == pages_total {
-                                            eps_start.set((n-1)*100 + 1);
-                                            eps_end.set(eps_total);
-                                        } else {
-                                            eps_start.set((n-1)*100 + 1);
-                                            eps_end.set((n-1)*100 + 100);
-                                        }
-
-                                        let eo_full = eo_full.clone();
-                                        let cur_page = cur_page.clone();
-                                        {
-                                            let eo_full = eo_full.clone();
-                                            wasm_bindgen_futures::spawn_local(async move {
-                                                let link = format!("https://api.jikan.moe/v4/anime/{}/episodes?page={}", mal_id, n);
-                                                let result = force_req::<AnimeEpisodeWrapper>(&link).await.unwrap().data;
-                                                let mut cur_eo = (*eo_full).clone();
-                                                let mut i = (n-1)*100;
-                                                result.into_iter().for_each(|eo| {
-                                                    cur_eo[i] = Some(eo);
-                                                    i += 1;
-                                                });
-
-                                                eo_full.set(cur_eo);
-
-                                            });
-                                        }
-
-                                        cur_page.set(n);
-                                        log!("eps page: ", n);
-                                    }}>{format!("{} - {}", (n-1)*100+1, {
-                                        if n == pages_total {
-                                            eps_total
-                                        } else {
-                                            n*100
-                                        }
-                                    })}</button>
-                                }
-                            }
-                        }).collect::<Html>()}
-                        </div>
-                    }
-
-                } else {
-                    html!{}
+                if page_count > 1 {
+                    <div class="eps-page-btns-wrapper">
+                    {(1..page_count + 1).map(|n| {
+                        let cur_page = cur_page.clone();
+                        let selected = n == *cur_page;
+                        let label = match episodes.page_range(n, listed) {
+                            Some((start, end)) => format!("{} - {}", start, end),
+                            None => format!("{} - …", (n as usize - 1) * EPISODES_PER_PAGE + 1),
+                        };
+                        html! {
+                            <button id={if selected { Some("page-btn-selected") } else { None }}
+                                class={format!("cursor-pointer page-btn hover-highlight {} {}", if selected { "selected" } else { "" }, theme)}
+                                onclick={move |_: MouseEvent| cur_page.set(n)}>{label}</button>
+                        }
+                    }).collect::<Html>()}
+                    </div>
                 }
-            }
 
                 // Handle torrent popup
                 <div class="eps-dwld-wrapper">
-                {(1..eps_total+1).into_iter().map(|n| {
+                {range_eps.clone().map(|n| {
                     let theme = theme.clone();
                     let theme_cloned = theme.clone();
                     let filters = filters.clone();
@@ -461,8 +439,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                 // Reset data state for ALL episodes
                                 {
                                     let torrent_data_state = torrent_data_state.clone();
-                                    let v = (*torrent_data_state).clone().iter_mut().map(|_| None).collect::<Vec<Option<Vec<Torrent>>>>();
-                                    torrent_data_state.set(v);
+                                    torrent_data_state.set(HashMap::new());
                                 }
                                 
                                 // Set class to keep track of button state
@@ -473,7 +450,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                     (false, true) => btn.set_attribute("class", &format!("eps-filter-btn eps-filter-active hover-highlight {}", &theme)).unwrap(),
                                     (_, false) => {
                                         btn.set_attribute("class", &format!("eps-filter-btn eps-filter cursor-disabled {}", &theme)).unwrap();
-                                        alert("'Enhanced Result' MUST be turned on to use these filters.");
+                                        alert(&t!("episodes.enhanced_result_required"));
                                         return ();
                                     }
                                 }
@@ -505,8 +482,8 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                     let get_torrent_full = get_torrent_full.clone();
                                     let torrents = get_torrents(&*ttl_cheap_en, &*ttl_cheap_def, n as u16, &filter_to_req, *get_torrent_full).await;
                                     log!(format!("{:#?}", &torrents));
-                                    let mut cur_t = (*torrent_data_state).clone().iter_mut().map(|_| None).collect::<Vec<Option<Vec<Torrent>>>>();
-                                    cur_t[n-1] = Some(torrents);
+                                    let mut cur_t = HashMap::new();
+                                    cur_t.insert(n, torrents);
                                     torrent_data_state.set(cur_t);
                                 });
                             })
@@ -538,7 +515,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                             {
                                 let torrent_data_state = torrent_data_state.clone();
                                 let mut v = (*torrent_data_state).clone();
-                                v[n-1] = None;
+                                v.remove(&n);
                                 torrent_data_state.set(v);
                             }
 
@@ -568,7 +545,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                 let torrents = get_torrents(&*ttl_cheap_en, &*ttl_cheap_def, n as u16, &filter_to_req, *get_torrent_full).await;
                                 log!(format!("{:#?}", &torrents));
                                 let mut cur_t = (*torrent_data_state).clone();
-                                cur_t[n-1] = Some(torrents);
+                                cur_t.insert(n, torrents);
                                 torrent_data_state.set(cur_t);
                             });
                         })
@@ -597,7 +574,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                             {
                                 let torrent_data_state = torrent_data_state.clone();
                                 let mut v = (*torrent_data_state).clone();
-                                v[n-1] = None;
+                                v.remove(&n);
                                 torrent_data_state.set(v);
                             }
 
@@ -627,7 +604,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                 let torrents = get_torrents(&*ttl_cheap_en, &*ttl_cheap_def, n as u16, &filter_to_req, *get_torrent_full).await;
                                 log!(format!("{:#?}", &torrents));
                                 let mut cur_t = (*torrent_data_state).clone();
-                                cur_t[n-1] = Some(torrents);
+                                cur_t.insert(n, torrents);
                                 torrent_data_state.set(cur_t);
                             });
                         })
@@ -657,7 +634,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                             {
                                 let torrent_data_state = torrent_data_state.clone();
                                 let mut v = (*torrent_data_state).clone();
-                                v[n-1] = None;
+                                v.remove(&n);
                                 torrent_data_state.set(v);
                             }
 
@@ -703,11 +680,11 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                         .collect::<Vec<String>>();
                                     log!(format!("{:?}", &cur_class));
                                     btn.set_attribute("class", cur_class.join(" ").as_str());
-                                    btn.set_attribute("title", "Enabled `Enhanced Result` to use the filters!");
+                                    btn.set_attribute("title", &t!("episodes.enhanced_result_hint"));
                                     filters.set(vec![]);
                                 }
                             } else {
-                                alert("Turning on \"Enhanced Result\" will improve torrent search result by querying nyaa.si synonyms of the anime's title. This is especially effective for sequels and anime with long or multiple titles.\n\nNote: Torrent fetching will take more time. Be sure to visit nyaa.si directly if the results do not match your expectation.");
+                                alert(&t!("episodes.enhanced_result_info"));
                                 get_torrent_full.set(true);
                                 let filter_buttons = web_sys::window().unwrap().document().unwrap().get_elements_by_class_name("eps-filter-btn");
                                 for i in (0..filter_buttons.length()) {
@@ -735,7 +712,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                                 let torrents = get_torrents(&*ttl_cheap_en, &*ttl_cheap_def, n as u16, &filter_to_req, !(*get_torrent_full)).await;
                                 log!(format!("{:#?}", &torrents));
                                 let mut cur_t = (*torrent_data_state).clone();
-                                cur_t[n-1] = Some(torrents);
+                                cur_t.insert(n, torrents);
                                 torrent_data_state.set(cur_t);
                             });
                         })
@@ -745,7 +722,7 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                     html!{
                         <dialog id={format!("eps-{}", n)} class={format!("eps-dwld {}", theme.clone())}>
                         <div class="dialog-close-button-container">
-                            <h2 class="content-ttl">{"Torrents from Nyaa"}</h2>
+                            <h2 class="content-ttl">{t!("episodes.torrents")}</h2>
                             <a class="a-btn dialog-close-button ripple" onclick={move |_| {
                                 let elem = web_sys::window().unwrap().document().unwrap().get_element_by_id(&format!("eps-{}", n)).unwrap();
                                 elem.set_attribute("class", &format!("eps-dwld {}", theme_cloned.clone())).unwrap();
@@ -757,14 +734,14 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                         </div>
 
                         <div class="eps-filter-wrapper">
-                            <h3 class="eps-filter-title">{"Filters"}</h3>
+                            <h3 class="eps-filter-title">{t!("episodes.filters")}</h3>
                             <button id={format!("enhanced-result-btn-{}", n)} class={format!("eps-filter{} hover-highlight {}", {
                                 if *get_torrent_full {
                                     "-active"
                                 } else {
                                     ""
                                 }
-                            },theme.clone())} onclick={enhanced_result}>{"Enhanced Result"}
+                            },theme.clone())} onclick={enhanced_result}>{t!("episodes.enhanced_result")}
                             </button>
                             {
                                     FILTER_TYPES
@@ -809,60 +786,56 @@ pub fn EpisodeCards(props: &EpisodeCardProps) -> Html {
                         </div>
 
                         <div class="client-query">
-                           <span>{"Custom Keyword"}</span><input type="text" id={format!("client-torrent-custom-query-{}", n)} class="client-torrent-custom-query" oninput={&update_client_query}/>
+                           <span>{t!("episodes.custom_keyword")}</span><input type="text" id={format!("client-torrent-custom-query-{}", n)} class="client-torrent-custom-query" oninput={&update_client_query}/>
                         </div>
 
                         <section class="torrent-cards-wrapper">
 
                         {
-                            if (n-1) < (*torrent_data_state).len() {
-                                match (*torrent_data_state)[(n-1) as usize] {
-                                    None => html!{<Loading/>},
-                                    Some(ref v) => {
-                                        if v.len() == 0 {
-                                            html!{"No torrents are found."}
-                                        } else {
-                                            (v.clone()).into_iter().map(|torrent| {
-                                                if (&torrent.title).to_lowercase().contains(&*client_query) {
-                                                    html!{
-                                                        <div class={format!("torrent-card obj-level-1 hover-highlight {}", theme.clone())}>
-                                                            <article class="torrent-card-info cursor-arrow">
-                                                                <section class="torrent-identity">
-                                                                    <span class="torrent-title">
-                                                                    <img class={format!("icon-{}", theme.clone())}src="./static/video.svg" width="14px" height="14px"/>
-                                                                    <a class="u_onhover" href={torrent.link_view} target="_blank">{&(torrent.title)}</a></span>
-                                                                </section>
-                                                                <section class="torrent-links">
-                                                                    <div class="torrent-download">
-                                                                        <img class={format!("icon-{}", theme.clone())}src="./static/checkmark.svg" width="14px" height="14px"/>
-                                                                    {&(torrent.download)}</div>
-                                                                    <span class="torrent-size-mb">
-                                                                    <img class={format!("icon-{}", theme.clone())}src="./static/diskette.svg" width="14px" height="14px"/>
-                                                                    {&(torrent.size_mb)}</span>
-                                                                </section>
-                                                            </article>
-                                                            <section class="torrent-download-buttons">
-                                                                <div class="torrent-link-magnet"><a class="a-btn ripple" href={(torrent.link_magnet).clone()}>
-                                                                    <img class={format!("icon-{}", theme.clone())}src="./static/torrent-magnet.svg" width="32px" height="32px"/>
-                                                                </a></div>
-                                                                <div class="torrent-link-torrent"><a class="a-btn ripple" href={(torrent.link_torrent).clone()}>
-                                                                    <img class={format!("icon-{}", theme.clone())}src="./static/torrent-download.svg" width="32px" height="32px"/>
-                                                                </a></div>
+                            match (*torrent_data_state).get(&n) {
+                                None => html!{<Loading/>},
+                                Some(v) => {
+                                    if v.len() == 0 {
+                                        html!{{t!("episodes.no_torrents")}}
+                                    } else {
+                                        (v.clone()).into_iter().map(|torrent| {
+                                            if (&torrent.title).to_lowercase().contains(&*client_query) {
+                                                html!{
+                                                    <div class={format!("torrent-card obj-level-1 hover-highlight {}", theme.clone())}>
+                                                        <article class="torrent-card-info cursor-arrow">
+                                                            <section class="torrent-identity">
+                                                                <span class="torrent-title">
+                                                                <img class={format!("icon-{}", theme.clone())}src="./static/video.svg" width="14px" height="14px"/>
+                                                                <a class="u_onhover" href={torrent.link_view} target="_blank">{&(torrent.title)}</a></span>
                                                             </section>
-                                                        </div>
-                                                    }
-                                                } else {
-                                                    html!{}
+                                                            <section class="torrent-links">
+                                                                <div class="torrent-download">
+                                                                    <img class={format!("icon-{}", theme.clone())}src="./static/checkmark.svg" width="14px" height="14px"/>
+                                                                {&(torrent.download)}</div>
+                                                                <span class="torrent-size-mb">
+                                                                <img class={format!("icon-{}", theme.clone())}src="./static/diskette.svg" width="14px" height="14px"/>
+                                                                {&(torrent.size_mb)}</span>
+                                                            </section>
+                                                        </article>
+                                                        <section class="torrent-download-buttons">
+                                                            <div class="torrent-link-magnet"><a class="a-btn ripple" href={(torrent.link_magnet).clone()}>
+                                                                <img class={format!("icon-{}", theme.clone())}src="./static/torrent-magnet.svg" width="32px" height="32px"/>
+                                                            </a></div>
+                                                            <div class="torrent-link-torrent"><a class="a-btn ripple" href={(torrent.link_torrent).clone()}>
+                                                                <img class={format!("icon-{}", theme.clone())}src="./static/torrent-download.svg" width="32px" height="32px"/>
+                                                            </a></div>
+                                                        </section>
+                                                    </div>
                                                 }
+                                            } else {
+                                                html!{}
+                                            }
 
-                                            }).collect::<Html>()
-                                        }
+                                        }).collect::<Html>()
                                     }
                                 }
-                            } else {
-                                html!{"Error retrieving episodes. Please refresh the page by pressing F5."}
                             }
-    }
+                        }
                         </section>
 
                         </dialog>
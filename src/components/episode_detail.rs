@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct EpisodeDetailProps {
+    pub mal_id: u64,
+    pub n: u32,
+}
+
+#[function_component(EpisodeDetailContent)]
+fn episode_detail_content(props: &EpisodeDetailProps) -> HtmlResult {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let nav = use_navigator().unwrap();
+    let (_, scope_dispatch) = use_store::<CommentScope>();
+    let (mal_id, n) = (props.mal_id, props.n);
+
+    let result = use_future_with((mal_id, n), |deps| async move {
+        let (mal_id, n) = *deps;
+        let anime_link = format!("https://api.jikan.moe/v4/anime/{}", mal_id);
+        let anime_req = force_req::<AnimeObjAsQueryResult>(&anime_link);
+        join!(fetch_episode_detail(mal_id, n), anime_req)
+    })?;
+
+    let go_to = |episode: u32| {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::EpisodeDetail { mal_id, n: episode }))
+    };
+
+    let open_anime = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::AnimeDetails { mal_id }))
+    };
+
+    let discuss = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| {
+            scope_dispatch.set(CommentScope {
+                episode: Some(n),
+                ..CommentScope::for_anime(mal_id as u32)
+            });
+            nav.push(&Route::AnimeDetails { mal_id });
+        })
+    };
+
+    let html_result = match &*result {
+        (Ok(Some(episode)), anime) => {
+            let anime = anime.as_ref().ok().map(|a| &a.data);
+            let anime_title = anime.map(|a| display_title(&cx, a));
+            let total = anime.and_then(|a| a.episodes).map(|e| e as u32);
+            let (prev, next) = adjacent_episodes(n, total);
+            let aired = episode.aired.as_deref().and_then(aired_local);
+            let title = display_episode_title(&cx, &AnimeEpisode::from(episode));
+
+            web_sys::window().unwrap().document().unwrap().set_title(&format!(
+                "{} | ANiNFO",
+                t!("episode.page_title", n = n, title = anime_title.clone().unwrap_or_default())
+            ));
+
+            html! {
+                <div class={format!("written-content episode-detail {}", theme)}>
+                    if let Some(anime_title) = &anime_title {
+                        <a class="a-btn u_onhover cursor-pointer episode-anime" onclick={open_anime}>{format!("← {}", anime_title)}</a>
+                    }
+                    <div class="content-header">
+                        <span><h2 class="content-ttl vc-text">{t!("episode.heading", n = n, title = title)}</h2></span>
+                    </div>
+                    <div class="episode-badges">
+                        if episode.filler {
+                            <span class="eps-badge filler">{t!("episodes.filler")}</span>
+                        }
+                        if episode.recap {
+                            <span class="eps-badge recap">{t!("episodes.recap")}</span>
+                        }
+                    </div>
+
+                    <div class="episode-meta">
+                        if let Some(romanji) = &episode.title_romanji {
+                            <div class="meta-header">{t!("episode.romanji")}<span class="meta-data">{romanji}</span></div>
+                        }
+                        if let Some(japanese) = &episode.title_japanese {
+                            <div class="meta-header">{t!("episode.japanese")}<span class="meta-data">{japanese}</span></div>
+                        }
+                        if let Some(aired) = aired {
+                            <div class="meta-header">{t!("episode.aired")}<span class="meta-data">{format!("{} {}", format_date(&aired.date()), aired.format("%H:%M"))}</span></div>
+                        }
+                        if let Some(secs) = episode.duration {
+                            <div class="meta-header">{t!("episode.duration")}<span class="meta-data">{t!("episode.minutes", count = duration_minutes(secs))}</span></div>
+                        }
+                    </div>
+
+                    <h3>{t!("episode.synopsis")}</h3>
+                    <p class="episode-synopsis">{episode.synopsis.clone().unwrap_or(t!("episode.no_synopsis"))}</p>
+
+                    <div class="episode-links">
+                        <a class="a-btn u_onhover cursor-pointer" onclick={discuss}>{t!("episodes.discuss")}</a>
+                        if let Some(url) = &episode.url {
+                            <a class="a-btn u_onhover" target="_blank" href={url.clone()}>{t!("episode.on_mal")}</a>
+                        }
+                        if let Some(url) = &episode.forum_url {
+                            <a class="a-btn u_onhover" target="_blank" href={url.clone()}>{t!("episode.forum")}</a>
+                        }
+                    </div>
+
+                    <div class="episode-nav">
+                        if let Some(prev) = prev {
+                            <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} onclick={go_to(prev)}>{t!("episode.prev", n = prev)}</button>
+                        }
+                        if let Some(next) = next {
+                            <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} onclick={go_to(next)}>{t!("episode.next", n = next)}</button>
+                        }
+                    </div>
+                </div>
+            }
+        }
+        (Ok(None), _) => html! {<ErrorPage app_err={AppErr::not_found()}/>},
+        (Err(e), _) => {
+            log!(format!("{:?}", e));
+            html! {<ErrorPage app_err={AppErr::gloo_default()}/>}
+        }
+    };
+
+    Ok(html_result)
+}
+
+/// One episode at `/anime/:mal_id/episode/:n`, with its synopsis and links to the
+/// episodes around it.
+#[function_component(EpisodeDetail)]
+pub fn episode_detail(props: &EpisodeDetailProps) -> Html {
+    html! {
+        <div class="search-result-wrapper">
+            <Suspense fallback={html!(<Loading/>)}>
+                <EpisodeDetailContent mal_id={props.mal_id} n={props.n}/>
+            </Suspense>
+        </div>
+    }
+}
@@ -37,9 +37,7 @@ impl FromStr for AppErr {
                 }
             }
             "nf" => {
-                return Ok(AppErr::NotFound(ErrMsg(
-                    "The feature you're looking for does not exist.".to_string(),
-                )))
+                return Ok(AppErr::NotFound(ErrMsg(t!("error.not_found"))))
             }
             _ => return Err("Unknown argument."),
         }
@@ -48,29 +46,27 @@ impl FromStr for AppErr {
 
 impl AppErr {
     pub fn js_default() -> AppErr {
-        AppErr::Js(ErrMsg("Unexpected application error occured. Please ensure you are using the latest version of Firefox, Edge or Chrome.".to_string()))
+        AppErr::Js(ErrMsg(t!("error.js")))
     }
 
     pub fn gloo_default() -> AppErr {
-        AppErr::Gloo(ErrMsg("Error in getting data from the server. This may occur because your network connection is unstable, or has been interrupted.".to_string()))
+        AppErr::Gloo(ErrMsg(t!("error.network")))
     }
 
     pub fn serde_default() -> AppErr {
-        AppErr::Serde(ErrMsg("Error in formatting data. Usually this occurs because you have made too many requests in a short span of time. You will be redirected to your previous page soon.".to_string()))
+        AppErr::Serde(ErrMsg(t!("error.serde")))
     }
 
     pub fn not_found() -> AppErr {
-        AppErr::NotFound(ErrMsg(
-            "The feature you're looking for does not exist.".to_string(),
-        ))
+        AppErr::NotFound(ErrMsg(t!("error.not_found")))
     }
 
     pub fn title(&self) -> String {
         match &self {
-            AppErr::Gloo(_) => "Network error".to_string(),
-            AppErr::Js(_) => "Javascript runtime error".to_string(),
-            AppErr::Serde(_) => "Too many request".to_string(),
-            AppErr::NotFound(_) => "404 Not Found".to_string(),
+            AppErr::Gloo(_) => t!("error.network_title"),
+            AppErr::Js(_) => t!("error.js_title"),
+            AppErr::Serde(_) => t!("error.serde_title"),
+            AppErr::NotFound(_) => t!("error.not_found_title"),
         }
     }
 
@@ -148,10 +144,109 @@ pub fn ErrorPage(props: &ErrorProps) -> Html {
     html! {
         <>
         <div class="written-content">
-        <h2 class="content-ttl">{"Error: "}{(*app_err).title()}</h2>
+        <h2 class="content-ttl">{t!("error.title")}{(*app_err).title()}</h2>
         <p>{(*app_err).msg()}</p>
-        <p><b>{"You will automatically be redirected to the previous page or the home page."}</b></p>
+        <p><b>{t!("error.redirect")}</b></p>
         </div>
         </>
     }
 }
+
+/// Why `/api/v1/users/register` refused to create an account.
+#[derive(PartialEq, Clone, Debug)]
+pub enum RegisterError {
+    UsernameTaken,
+    InvalidUsername(String),
+    WeakPassword(String),
+    Server(String),
+}
+
+/// Error body of the backend, e.g. `{"code": "weak_password", "message": "..."}`.
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    code: String,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl RegisterError {
+    pub fn from_response(status: u16, body: String) -> RegisterError {
+        let parsed = serde_json::from_str::<ApiErrorBody>(&body).ok();
+        let message = |fallback: String| {
+            parsed
+                .as_ref()
+                .and_then(|p| p.message.clone())
+                .filter(|m| !m.trim().is_empty())
+                .unwrap_or(fallback)
+        };
+
+        match (status, parsed.as_ref().map(|p| p.code.as_str())) {
+            (_, Some("username_taken")) | (409, _) => RegisterError::UsernameTaken,
+            (_, Some("invalid_username")) => {
+                RegisterError::InvalidUsername(message(t!("auth.invalid_username")))
+            }
+            (_, Some("weak_password")) => {
+                RegisterError::WeakPassword(message(t!("auth.weak_password")))
+            }
+            // A malformed request without a structured body; its text is not for users
+            (400 | 422, _) if parsed.is_none() => {
+                RegisterError::Server(t!("auth.register_rejected"))
+            }
+            _ => RegisterError::Server(message(t!("auth.register_failed", status = status))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_error_from_response() {
+        assert_eq!(
+            RegisterError::from_response(400, r#"{"code": "username_taken"}"#.to_string()),
+            RegisterError::UsernameTaken
+        );
+        assert_eq!(
+            RegisterError::from_response(409, "".to_string()),
+            RegisterError::UsernameTaken
+        );
+        assert_eq!(
+            RegisterError::from_response(422, r#"{"code": "invalid_username"}"#.to_string()),
+            RegisterError::InvalidUsername(t!("auth.invalid_username"))
+        );
+        assert_eq!(
+            RegisterError::from_response(
+                422,
+                r#"{"code": "weak_password", "message": "Too short."}"#.to_string()
+            ),
+            RegisterError::WeakPassword("Too short.".to_string())
+        );
+        assert_eq!(
+            RegisterError::from_response(
+                422,
+                r#"{"code": "weak_password", "message": " "}"#.to_string()
+            ),
+            RegisterError::WeakPassword(t!("auth.weak_password"))
+        );
+        assert_eq!(
+            RegisterError::from_response(400, "Json deserialize error".to_string()),
+            RegisterError::Server(t!("auth.register_rejected"))
+        );
+        assert_eq!(
+            RegisterError::from_response(422, "".to_string()),
+            RegisterError::Server(t!("auth.register_rejected"))
+        );
+        assert_eq!(
+            RegisterError::from_response(500, "Internal Server Error".to_string()),
+            RegisterError::Server(t!("auth.register_failed", status = 500))
+        );
+        assert_eq!(
+            RegisterError::from_response(
+                503,
+                r#"{"code": "maintenance", "message": "Back soon."}"#.to_string()
+            ),
+            RegisterError::Server("Back soon.".to_string())
+        );
+    }
+}
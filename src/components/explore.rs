@@ -21,12 +21,24 @@ fn content() -> HtmlResult {
         let cur_page = cur_page.clone();
 
         use_future_with(
-            ((*cx).nsfw, qf.clone(), *qs, cur_page.0.clone()),
+            (
+                (*cx).nsfw,
+                qf.clone(),
+                *qs,
+                cur_page.0.clone(),
+                (*cx).scroll_mode,
+            ),
             |_| async move {
+                // The infinite grid appends from its first page on, so it always starts at 1
+                let page = if (*cx).scroll_mode == ScrollMode::Infinite {
+                    1
+                } else {
+                    cur_page.0 .0
+                };
                 let link = format!(
                     "https://api.jikan.moe/v4/anime?sfw={}&page={}{}{}&",
                     !(*cx).nsfw,
-                    cur_page.0 .0,
+                    page,
                     (*qf).to_params(),
                     (*qs).to_params()
                 );
@@ -43,9 +55,9 @@ fn content() -> HtmlResult {
                     <div class="search-result-wrapper">
                     <div class="written-content">
                         <div class="content-header">
-                            <span><h2 class="content-ttl vc-text">{"Oops!"}</h2></span>
+                            <span><h2 class="content-ttl vc-text">{t!("search.oops")}</h2></span>
                         </div>
-                        <p style="text-align: center !important;">{"No anime matching your search query is found."}</p>
+                        <p style="text-align: center !important;">{t!("search.no_match")}</p>
                     </div>
                     </div>
                 }
@@ -58,68 +70,86 @@ fn content() -> HtmlResult {
                 html! {
                     <div class="search-result-wrapper" onload={|_| {log!("result-wrapper loaded.")}}>
                         <div class="content-header">
-                            <span><h2 class="content-ttl vc-text">{"Exploration Result"}</h2></span>
+                            <span><h2 class="content-ttl vc-text">{t!("explore.result")}</h2></span>
                         </div>
 
-                        <div class="page-btn-wrapper">
                         {
-                            html! {
+                            if (*cx).scroll_mode == ScrollMode::Infinite {
+                                let base_link = format!(
+                                    "https://api.jikan.moe/v4/anime?sfw={}{}{}",
+                                    !(*cx).nsfw,
+                                    (*q_filter.0).to_params(),
+                                    (*q_sort.0).to_params()
+                                );
+                                html!{
+                                    <InfiniteAnimeGrid list_key={base_link.clone()} base_link={base_link} first_page={v.clone()}/>
+                                }
+                            } else {
+                                html!{
+                                <>
+                                <div class="page-btn-wrapper">
                                 {
-                                    (1..last_page+1).into_iter().map(|n| {
-                                        let (cur_page_s, cur_page_disp) = cur_page.clone();
-                                        if n as u32 == (*(cur_page_s)).0 {
-                                            html! {
-                                                <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", &theme)} onclick={move |_: MouseEvent| {
-                                                    cur_page_disp.set(ExplorePage(n as u32));
-                                                    web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
-                                                }}>{n}</button>
-                                            }
-                                        } else {
-                                            html! {
-                                                <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
-                                                    cur_page_disp.set(ExplorePage(n as u32));
-                                                    web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
-                                                }}>{n}</button>
-                                            }
+                                    html! {
+                                        {
+                                            (1..last_page+1).into_iter().map(|n| {
+                                                let (cur_page_s, cur_page_disp) = cur_page.clone();
+                                                if n as u32 == (*(cur_page_s)).0 {
+                                                    html! {
+                                                        <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", &theme)} onclick={move |_: MouseEvent| {
+                                                            cur_page_disp.set(ExplorePage(n as u32));
+                                                            web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
+                                                        }}>{n}</button>
+                                                    }
+                                                } else {
+                                                    html! {
+                                                        <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
+                                                            cur_page_disp.set(ExplorePage(n as u32));
+                                                            web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
+                                                        }}>{n}</button>
+                                                    }
+                                                }
+                                            }).collect::<Html>()
                                         }
-                                    }).collect::<Html>()
+                                    }
                                 }
-                            }
-                        }
-                        </div>
+                                </div>
 
-                        <div class="cards-wrapper">
-                            {into_anime_cards(&(v.data))}
-                        </div>
+                                <div class="cards-wrapper">
+                                    {into_anime_cards(&(v.data))}
+                                </div>
 
-                        <div class="page-btn-wrapper">
-                        {
-                            html! {
+                                <div class="page-btn-wrapper">
                                 {
-                                    (1..last_page+1).into_iter().map(|n| {
-                                        let (cur_page_s, cur_page_disp) = cur_page.clone();
-                                        if n as u32 == cur_page_s.0 {
-                                            html! {
-                                                <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", &theme)} onclick={move |_: MouseEvent| {
-                                                    log!("btn pushed");
-                                                    cur_page_disp.set(ExplorePage(n as u32));
-                                                    web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
-                                                }}>{n}</button>
-                                            }
-                                        } else {
-                                            html! {
-                                                <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
-                                                    log!("btn pushed");
-                                                    cur_page_disp.set(ExplorePage(n as u32));
-                                                    web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
-                                                }}>{n}</button>
-                                            }
+                                    html! {
+                                        {
+                                            (1..last_page+1).into_iter().map(|n| {
+                                                let (cur_page_s, cur_page_disp) = cur_page.clone();
+                                                if n as u32 == cur_page_s.0 {
+                                                    html! {
+                                                        <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", &theme)} onclick={move |_: MouseEvent| {
+                                                            log!("btn pushed");
+                                                            cur_page_disp.set(ExplorePage(n as u32));
+                                                            web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
+                                                        }}>{n}</button>
+                                                    }
+                                                } else {
+                                                    html! {
+                                                        <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
+                                                            log!("btn pushed");
+                                                            cur_page_disp.set(ExplorePage(n as u32));
+                                                            web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
+                                                        }}>{n}</button>
+                                                    }
+                                                }
+                                            }).collect::<Html>()
                                         }
-                                    }).collect::<Html>()
+                                    }
+                                }
+                                </div>
+                                </>
                                 }
                             }
                         }
-                        </div>
 
                     </div>
                 }
@@ -179,7 +209,7 @@ pub fn explore_anime() -> Html {
     let qf = use_store::<QueryFilter>();
     let cx = use_context::<AppContext>().unwrap();
     let cur_page = use_store::<ExplorePage>();
-    use_title("Explore Anime | ANiNFO".to_string());
+    use_title(format!("{} | ANiNFO", t!("explore.page_title")));
 
     let reset_genre = {
         let qf = qf.clone();
@@ -231,7 +261,7 @@ pub fn explore_anime() -> Html {
     html! {
         <>
         <section class={format!("explore-filter-wrapper max-1600px-resp {}", &handle_theme(&cx))}>
-            <h2 class="filter-header">{"Anime Filters"}</h2>
+            <h2 class="filter-header">{t!("explore.filters")}</h2>
             <div id="genre-include-header" class={format!("explore-filter-header obj-level-1 hover-highlight {}", &handle_theme(&cx))} onclick={
                 {
                     let cx = cx.clone();
@@ -257,12 +287,12 @@ pub fn explore_anime() -> Html {
             {
                 if ((*(qf.0)).genres).len() == 0 {
                     html!{
-                        <h3 class="disp-flex-normal">{"Genres"}</h3>
+                        <h3 class="disp-flex-normal">{t!("explore.genres")}</h3>
                     }
                 } else {
                     html!{
                         <>
-                        <h3 class="disp-flex"><span>{"Genres"}
+                        <h3 class="disp-flex"><span>{t!("explore.genres")}
                         <span class="header-small">
                         {": "}
                         {
@@ -274,7 +304,7 @@ pub fn explore_anime() -> Html {
                                 }
                             })
                             .collect::<Html>()}
-                            </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_genre}>{"Reset"}</a>
+                            </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_genre}>{t!("explore.reset")}</a>
                         </h3>
                         </>}
                 }
@@ -363,14 +393,14 @@ pub fn explore_anime() -> Html {
                     html!{
                         <h3 class="disp-flex-normal">
 
-                        {"Genres to Exclude"}</h3>
+                        {t!("explore.genres_exclude")}</h3>
                     }
                 } else {
                     html!{
                         <>
                         <h3 class="disp-flex">
                         <span>
-                        {"Genres to Exclude"}
+                        {t!("explore.genres_exclude")}
                         <span class="header-small">
                         {": "}
                         {
@@ -382,7 +412,7 @@ pub fn explore_anime() -> Html {
                                 }
                             })
                             .collect::<Html>()}
-                            </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_genre_excl}>{"Reset"}</a>
+                            </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_genre_excl}>{t!("explore.reset")}</a>
                         </h3>
                         </>}
                 }
@@ -495,14 +525,14 @@ pub fn explore_anime() -> Html {
                 // }
 
                 match ((*(qf.0)).start_date.as_ref(), (*(qf.0)).end_date.as_ref()) {
-                    (None, None) => html!{<h3>{"Years"}</h3>},
+                    (None, None) => html!{<h3>{t!("explore.years")}</h3>},
                     (Some(ref y), None) => {
                         html!{
                             <>
-                            <h3 class="disp-flex"><span>{"Years"}
+                            <h3 class="disp-flex"><span>{t!("explore.years")}
                                 <span class="header-small">
                                 {": "}{&y[..4]}{" - "}
-                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{"Reset"}</a>
+                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{t!("explore.reset")}</a>
                             </h3>
                             </>
                         }
@@ -510,7 +540,7 @@ pub fn explore_anime() -> Html {
                     (Some(ref y1), Some(ref y2)) => {
                         html!{
                             <>
-                            <h3 class="disp-flex"><span>{"Years"}
+                            <h3 class="disp-flex"><span>{t!("explore.years")}
                                 <span class="header-small">
                                 {
                                     if y1[..4] == y2[..4] {
@@ -519,7 +549,7 @@ pub fn explore_anime() -> Html {
                                         html!{<>{": "}{&y1[..4]}{" - "}{&y2[..4]}</>}
                                     }
                                 }
-                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{"Reset"}</a>
+                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{t!("explore.reset")}</a>
                             </h3>
                             </>
                         }
@@ -527,10 +557,10 @@ pub fn explore_anime() -> Html {
                     (None, Some(ref y)) => {
                         html!{
                             <>
-                            <h3 class="disp-flex"><span>{"Years"}
+                            <h3 class="disp-flex"><span>{t!("explore.years")}
                                 <span class="header-small">
                                 {": "}{"- "}{&y[..4]}
-                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{"Reset"}</a>
+                                </span></span><a class="header-small u_onhover cursor-pointer" onclick={reset_year}>{t!("explore.reset")}</a>
                             </h3>
                             </>
                         }
@@ -541,14 +571,14 @@ pub fn explore_anime() -> Html {
 
             <section id="year-filter" class={format!("explore-filters {}", &handle_theme(&cx))} >
                 <div>
-                    <label for="start-year">{"Year start "}</label>
+                    <label for="start-year">{t!("explore.year_start")}</label>
                     <select id="start-year" oninput={start_year_onselect}>
                     {
                         match ( (*(qf.0)).start_date.as_ref(), (*(qf.0)).end_date.as_ref() ) {
                             (None, None) => {
                                 html!{
                                             <>
-                                            <option selected={true}>{"N/A"}</option>
+                                            <option selected={true} value="N/A">{t!("explore.any_year")}</option>
                                             {
                                                 (1900..cur_year()+1).rev().map(|n| {
                                                     html!{<option value={format!("{}", n)}>{n}</option>}
@@ -561,7 +591,7 @@ pub fn explore_anime() -> Html {
                                 let y_start = y_start[..4].parse::<u32>().unwrap();
                                 html!{
                                     <>
-                                    <option selected={true}>{"N/A"}</option>
+                                    <option selected={true} value="N/A">{t!("explore.any_year")}</option>
                                     {
                                         (1900..cur_year()+1).rev().map(|n| {
                                             if n == y_start {
@@ -591,7 +621,7 @@ pub fn explore_anime() -> Html {
                                 let y_end = y_end[..4].parse::<u32>().expect("Year must be valid.");
                                 html!{
                                     <>
-                                    <option selected={true}>{"N/A"}</option>
+                                    <option selected={true} value="N/A">{t!("explore.any_year")}</option>
                                     {
                                         (y_end..cur_year()+1).rev().map(|n| {
                                             html!{<option value={format!("{}", n)}>{n}</option>}
@@ -608,14 +638,14 @@ pub fn explore_anime() -> Html {
                 </div>
 
                 <div>
-                    <label for="end-year">{"Year end "}</label>
+                    <label for="end-year">{t!("explore.year_end")}</label>
                     <select id="end-year" oninput={end_year_onselect}>
                     {
                         match ( (*(qf.0)).start_date.as_ref(), (*(qf.0)).end_date.as_ref() ) {
                             (None, None) => {
                                 html!{
                                             <>
-                                            <option selected={true}>{"N/A"}</option>
+                                            <option selected={true} value="N/A">{t!("explore.any_year")}</option>
                                             {
                                                 (1900..cur_year()+1).rev().map(|n| {
                                                     html!{<option value={format!("{}", n)}>{n}</option>}
@@ -628,7 +658,7 @@ pub fn explore_anime() -> Html {
                                 let y_start = y_start[..4].parse::<u32>().unwrap();
                                 html!{
                                     <>
-                                    <option selected={true}>{"N/A"}</option>
+                                    <option selected={true} value="N/A">{t!("explore.any_year")}</option>
                                     {
                                         (y_start..cur_year()+1).rev().map(|n| {
                                             html!{<option value={format!("{}", n)}>{n}</option>}
@@ -0,0 +1,175 @@
+use crate::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+struct FeedItemProps {
+    activity: FeedActivity,
+}
+
+#[function_component(FeedItem)]
+fn feed_item(props: &FeedItemProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let nav = use_navigator().unwrap();
+    let a = &props.activity;
+
+    let open_profile = {
+        let nav = nav.clone();
+        let username = a.username.clone();
+        Callback::from(move |_: MouseEvent| {
+            nav.push(&Route::UserProfile {
+                username: username.clone(),
+            });
+        })
+    };
+
+    let open_anime = {
+        let mal_id = a.anime_id;
+        Callback::from(move |_: MouseEvent| nav.push(&Route::AnimeDetails { mal_id }))
+    };
+
+    html! {
+        <div class={format!("feed-item obj-level-1 {}", theme)}>
+            if let Some(img) = &a.anime_img {
+                <img class="feed-thumb cursor-pointer" src={img.clone()} alt={a.anime_title.clone()} loading="lazy" onclick={open_anime.clone()}/>
+            }
+            <div class="feed-body">
+                <span>
+                    <a class="username-link" onclick={open_profile}>{&a.username}</a>
+                    {" "}{t!(a.kind.label_key())}{" "}
+                    <a class="a-btn u_onhover cursor-pointer" onclick={open_anime}><b>{&a.anime_title}</b></a>
+                    if let Some(score) = a.score {
+                        {" "}<span class="feed-score">{t!("feed.score", score = score)}</span>
+                    }
+                </span>
+                if let Some(comment) = &a.comment {
+                    <span class="comment">{render_markdown(comment)}</span>
+                }
+            </div>
+        </div>
+    }
+}
+
+#[derive(Clone, PartialEq, Default)]
+struct FeedState {
+    activities: Vec<FeedActivity>,
+    next_cursor: Option<String>,
+    /// Whether the first page has arrived.
+    loaded: bool,
+    loading: bool,
+    failed: bool,
+}
+
+/// Recent activity of the users the viewer follows, at `/feed`.
+#[function_component(Feed)]
+pub fn feed() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let state = use_state(FeedState::default);
+    use_title(format!("{} | ANiNFO", t!("feed.title")));
+
+    let load_page = {
+        let cx = cx.clone();
+        let state = state.clone();
+        move |cursor: Option<String>| {
+            let cx = cx.clone();
+            let state = state.clone();
+            state.set(FeedState {
+                loading: true,
+                failed: false,
+                ..(*state).clone()
+            });
+            let before = (*state).clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let first = cursor.is_none();
+                match fetch_feed(&cx, cursor).await {
+                    Ok(page) => state.set(FeedState {
+                        activities: if first {
+                            page.activities
+                        } else {
+                            append_feed_page(&before.activities, page.activities)
+                        },
+                        next_cursor: page.next_cursor,
+                        loaded: true,
+                        loading: false,
+                        failed: false,
+                    }),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        state.set(FeedState {
+                            loading: false,
+                            failed: true,
+                            ..before
+                        });
+                    }
+                }
+            });
+        }
+    };
+
+    {
+        let load_page = load_page.clone();
+        use_effect_with((*cx).jwt.is_some(), move |logged_in| {
+            if *logged_in {
+                load_page(None);
+            }
+        });
+    }
+
+    let load_more = {
+        let state = state.clone();
+        Callback::from(move |_: MouseEvent| {
+            if !state.loading {
+                load_page(state.next_cursor.clone());
+            }
+        })
+    };
+
+    let content = if (*cx).jwt.is_none() {
+        html! {<span class="no-result">{t!("feed.login_required")}</span>}
+    } else if !state.loaded && state.failed {
+        html! {<span class="no-result">{t!("feed.failed")}</span>}
+    } else if !state.loaded {
+        html! {<Loading/>}
+    } else if state.activities.is_empty() {
+        html! {<span class="no-result">{t!("feed.empty")}</span>}
+    } else {
+        html! {
+            <>
+            {
+                group_feed_by_day(&state.activities).into_iter().map(|(day, activities)| html! {
+                    <div class="feed-day">
+                        if let Some(day) = day {
+                            <h3>{format_date(&day)}</h3>
+                        }
+                        {
+                            activities.into_iter().map(|activity| {
+                                let key = activity.id;
+                                html! {<FeedItem key={key} {activity}/>}
+                            }).collect::<Html>()
+                        }
+                    </div>
+                }).collect::<Html>()
+            }
+            if state.failed {
+                <span class="warning-plaintext">{t!("feed.failed")}</span>
+            }
+            if state.next_cursor.is_some() {
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} disabled={state.loading} onclick={load_more}>
+                    {if state.loading { t!("feed.loading") } else { t!("feed.load_more") }}
+                </button>
+            }
+            </>
+        }
+    };
+
+    html! {
+        <div class="search-result-wrapper">
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("feed.title")}</h2></span>
+            </div>
+            <div class="feed">
+                {content}
+            </div>
+        </div>
+    }
+}
@@ -18,7 +18,7 @@ pub fn footer() -> Html {
         <p id="pp-link"><a class="u_onhover" onclick={move |_| {
             web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
             nav.push(&Route::PrivacyPolicy);
-        }}>{"Privacy Policy"}</a>{" | Best viewed using "}<a class="u_onhover" href="https://www.mozilla.org/en-US/firefox/new/">{"Mozilla Firefox."}</a></p>
+        }}>{t!("footer.privacy")}</a>{t!("footer.best_viewed")}<a class="u_onhover" href="https://www.mozilla.org/en-US/firefox/new/">{"Mozilla Firefox."}</a></p>
         </div>
 
         <div class="footer-right">
@@ -0,0 +1,175 @@
+use gloo::storage::{SessionStorage, Storage};
+
+use crate::prelude::*;
+
+/// Only the most recently added favourites are used, to keep the number of Jikan requests low.
+const MAX_SOURCES: usize = 5;
+/// Candidates whose genres are looked up for the overlap score.
+const GENRE_LOOKUPS: usize = 12;
+const MAX_PICKS: usize = 10;
+/// Picks are worked out once per session for a given list of favourites.
+const PICKS_CACHE_KEY: &str = "aninfo-for-you";
+
+#[derive(PartialEq, Serialize, Deserialize, Debug, Clone)]
+struct ForYouPick {
+    entry: AnimeRecObj,
+    /// Titles of the favourites behind the pick, explained in the current language
+    /// when rendered.
+    because: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedPicks {
+    fav_ids: Vec<u32>,
+    /// The reasons name favourites by their display title, so they depend on it too.
+    title_order: Vec<TitleKind>,
+    picks: Vec<ForYouPick>,
+}
+
+/// Lookups go through `utils::lookups`, which caches them and keeps them within
+/// Jikan's rate limit.
+async fn fetch_picks(
+    cx: &AppContext,
+    favs: Vec<UserAnimeResponse>,
+) -> Result<Vec<ForYouPick>, gloo::net::Error> {
+    let fav_ids = favs
+        .iter()
+        .map(|f| f.anime_id as u32)
+        .collect::<Vec<u32>>();
+
+    if let Ok(cached) = SessionStorage::get::<CachedPicks>(PICKS_CACHE_KEY) {
+        if cached.fav_ids == fav_ids && cached.title_order == (*cx).title_order {
+            return Ok(cached.picks);
+        }
+    }
+
+    let mut sources = Vec::new();
+    let mut fav_objs = Vec::new();
+    let mut last_err = None;
+    for fav in favs.iter().rev().take(MAX_SOURCES) {
+        // A favourite that fails to load is left out rather than emptying the section
+        let looked_up = match lookup_anime(fav.anime_id).await {
+            Ok(ao) => lookup_recommendations(fav.anime_id).await.map(|recs| (ao, recs)),
+            Err(e) => Err(e),
+        };
+        match looked_up {
+            Ok((ao, recs)) => {
+                sources.push((display_title(cx, &ao), recs));
+                fav_objs.push(ao);
+            }
+            Err(e) => {
+                log!(format!("{:?}", e));
+                last_err = Some(e);
+            }
+        }
+    }
+    if sources.is_empty() {
+        if let Some(e) = last_err {
+            return Err(e);
+        }
+    }
+
+    let counts = genre_counts(&fav_objs);
+    let mut scored = Vec::new();
+    for c in aggregate_recommendations(&sources, &fav_ids)
+        .into_iter()
+        .take(GENRE_LOOKUPS)
+    {
+        // A failed lookup only costs the pick its genre bonus
+        let overlap = match lookup_anime(c.entry.mal_id as i32).await {
+            Ok(ao) => genre_overlap(&ao.genres, &counts, fav_objs.len()),
+            Err(_) => 0.0,
+        };
+        scored.push((rec_score(&c, overlap), c));
+    }
+
+    scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    let picks = scored
+        .into_iter()
+        .take(MAX_PICKS)
+        .map(|(_, c)| ForYouPick {
+            entry: c.entry,
+            because: c.because,
+        })
+        .collect::<Vec<ForYouPick>>();
+
+    // Partial results are not kept, so the next visit tries the missing sources again
+    if last_err.is_none() {
+        let cached = CachedPicks {
+            fav_ids,
+            title_order: (*cx).title_order.clone(),
+            picks: picks.clone(),
+        };
+        if let Err(e) = SessionStorage::set(PICKS_CACHE_KEY, &cached) {
+            log!(format!("{:?}", e));
+        }
+    }
+    Ok(picks)
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct ForYouContentProp {
+    favs: Vec<UserAnimeResponse>,
+}
+
+#[function_component(ForYouContent)]
+fn for_you_content(props: &ForYouContentProp) -> HtmlResult {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+
+    let result: UseFutureHandle<Result<Vec<ForYouPick>, gloo::net::Error>> = {
+        let cx = cx.clone();
+        use_future_with(
+            (props.favs.clone(), (*cx).title_order.clone()),
+            |deps| async move { fetch_picks(&cx, deps.0.clone()).await },
+        )?
+    };
+
+    let html_result = match (*result).as_ref() {
+        Ok(picks) if !picks.is_empty() => html! {
+            <div id="for-you-cards-wrapper" class="cards-wrapper b-y">
+            {
+                picks.iter().map(|p| html! {
+                    <div class="for-you-pick">
+                        <AnimeCardMinimal ao={p.entry.clone()}/>
+                        <p class={format!("for-you-reason {}", theme)}>{explain_pick(&p.because)}</p>
+                    </div>
+                }).collect::<Html>()
+            }
+            </div>
+        },
+        Ok(_) => html! {
+            <span class="no-result">{t!("for_you.more_favourites")}</span>
+        },
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {
+                <span class="no-result">{t!("for_you.unavailable")}</span>
+            }
+        }
+    };
+
+    Ok(html_result)
+}
+
+/// "For you" section of `Home`, built from the recommendations of the user's favourites.
+#[function_component(ForYou)]
+pub fn for_you() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+
+    let favs = match (*cx).fav_anime.as_ref() {
+        Some(v) if !v.is_empty() => v.clone(),
+        _ => return html! {},
+    };
+
+    html! {
+        <div class={format!("for-you-wrapper obj-level-1")}>
+            <div class="header-home">
+                <h2 class="content-ttl">{t!("for_you.title")}</h2>
+            </div>
+            <Suspense fallback={html!(<Loading/>)}>
+                <ForYouContent {favs}/>
+            </Suspense>
+        </div>
+    }
+}
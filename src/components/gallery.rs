@@ -0,0 +1,199 @@
+use gloo::events::EventListener;
+
+use crate::prelude::*;
+
+/// Thumbnails are at most this wide, so the browser can pick the small size.
+const THUMB_SIZES: &str = "(max-width: 600px) 45vw, 180px";
+const LIGHTBOX_SIZES: &str = "90vw";
+
+fn picture(images: &AnimeImages, alt: String, sizes: &'static str) -> Html {
+    let webp = image_srcset(&images.webp);
+    let jpg = image_srcset(&images.jpg);
+    let src = images.jpg.image_url.clone().or(largest_image(images));
+
+    html! {
+        <picture>
+            if !webp.is_empty() {
+                <source type="image/webp" srcset={webp} sizes={sizes}/>
+            }
+            <img loading="lazy" decoding="async" {src} alt={alt} sizes={sizes}
+                srcset={(!jpg.is_empty()).then_some(jpg)}/>
+        </picture>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct PictureGalleryProps {
+    pub pictures: Vec<AnimeImages>,
+    /// Name of what is pictured, for alt texts.
+    pub title: String,
+}
+
+/// Thumbnail grid that opens a lightbox. While it is open, the arrow keys step
+/// through the pictures and Esc closes it.
+#[function_component(PictureGallery)]
+pub fn picture_gallery(props: &PictureGalleryProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let open = use_state(|| None::<usize>);
+    let len = props.pictures.len();
+
+    {
+        let open = open.clone();
+        use_effect_with(*open, move |index| {
+            let listener = index.map(|index| {
+                let window = web_sys::window().unwrap();
+                EventListener::new(&window, "keydown", move |e| {
+                    let e = e.dyn_ref::<KeyboardEvent>().unwrap();
+                    match LightboxKey::from_key(&e.key()) {
+                        Some(LightboxKey::Previous) => open.set(Some(lightbox_step(index, len, -1))),
+                        Some(LightboxKey::Next) => open.set(Some(lightbox_step(index, len, 1))),
+                        Some(LightboxKey::Close) => open.set(None),
+                        None => return,
+                    }
+                    e.prevent_default();
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    let alt = |i: usize| t!("gallery.alt", title = props.title.clone(), n = i + 1);
+
+    let thumbs = props.pictures.iter().enumerate().map(|(i, images)| {
+        let open = open.clone();
+        html! {
+            <button class={format!("gallery-thumb hover-highlight cursor-pointer obj-level-2 {}", theme)}
+                onclick={move |_| open.set(Some(i))}>
+                {picture(images, alt(i), THUMB_SIZES)}
+            </button>
+        }
+    }).collect::<Html>();
+
+    let lightbox = match *open {
+        Some(index) if index < len => {
+            let close = {
+                let open = open.clone();
+                Callback::from(move |_: MouseEvent| open.set(None))
+            };
+            let step = |delta: isize| {
+                let open = open.clone();
+                Callback::from(move |e: MouseEvent| {
+                    e.stop_propagation();
+                    open.set(Some(lightbox_step(index, len, delta)));
+                })
+            };
+            html! {
+                <div class="lightbox" role="dialog" aria-modal="true" onclick={close.clone()}>
+                    <button class="lightbox-btn lightbox-close cursor-pointer" aria-label={t!("gallery.close")} onclick={close}>{"✕"}</button>
+                    if len > 1 {
+                        <button class="lightbox-btn lightbox-prev cursor-pointer" aria-label={t!("gallery.previous")} onclick={step(-1)}>{"‹"}</button>
+                    }
+                    <div class="lightbox-picture" onclick={|e: MouseEvent| e.stop_propagation()}>
+                        {picture(&props.pictures[index], alt(index), LIGHTBOX_SIZES)}
+                    </div>
+                    if len > 1 {
+                        <button class="lightbox-btn lightbox-next cursor-pointer" aria-label={t!("gallery.next")} onclick={step(1)}>{"›"}</button>
+                    }
+                    <span class="lightbox-count">{t!("gallery.position", n = index + 1, total = len)}</span>
+                </div>
+            }
+        }
+        _ => html! {},
+    };
+
+    html! {
+        <>
+            <div class="gallery-grid">{thumbs}</div>
+            {lightbox}
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct AnimePicturesProps {
+    pub mal_id: u64,
+    pub title: String,
+}
+
+#[function_component(AnimePicturesContent)]
+fn anime_pictures_content(props: &AnimePicturesProps) -> HtmlResult {
+    let result = use_future_with(props.mal_id, |mal_id| fetch_anime_pictures(*mal_id))?;
+
+    Ok(match &*result {
+        Ok(pictures) if !pictures.is_empty() => html! {
+            <PictureGallery pictures={pictures.clone()} title={props.title.clone()}/>
+        },
+        Ok(_) => html! {<span class="no-result">{t!("gallery.unavailable")}</span>},
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {<span class="no-result">{t!("gallery.unavailable")}</span>}
+        }
+    })
+}
+
+/// Pictures section of `AnimeDetails`, fetched separately so it does not hold the
+/// page up.
+#[function_component(AnimePictures)]
+pub fn anime_pictures(props: &AnimePicturesProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+
+    html! {
+        <section class="gallery-wrapper">
+            <div class="ad-section-header">
+            <img loading="lazy" class={format!("ad-section-icon hideable icon-{}", theme)} src="./static/tv.svg"/><h2 id="ad-section-header" class="content-ttl">{t!("gallery.title")}</h2></div>
+            <Suspense fallback={html!(<Loading/>)}>
+                <AnimePicturesContent mal_id={props.mal_id} title={props.title.clone()}/>
+            </Suspense>
+        </section>
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct CharacterPicturesProps {
+    pub char_id: u64,
+    pub name: String,
+    pub onclose: Callback<()>,
+}
+
+#[function_component(CharacterPicturesContent)]
+fn character_pictures_content(props: &CharacterPicturesProps) -> HtmlResult {
+    let result = use_future_with(props.char_id, |char_id| fetch_character_pictures(*char_id))?;
+
+    Ok(match &*result {
+        Ok(pictures) if !pictures.is_empty() => html! {
+            <PictureGallery pictures={pictures.clone()} title={props.name.clone()}/>
+        },
+        Ok(_) => html! {<span class="no-result">{t!("gallery.unavailable")}</span>},
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {<span class="no-result">{t!("gallery.unavailable")}</span>}
+        }
+    })
+}
+
+/// Panel with a character's pictures, fetched when it is opened. It is rendered
+/// into `<body>` since the character strip masks its overflow.
+#[function_component(CharacterPictures)]
+pub fn character_pictures(props: &CharacterPicturesProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let close = props.onclose.reform(|_: MouseEvent| ());
+
+    let panel = html! {
+        <div class="gallery-modal" onclick={close.clone()}>
+            <div class={format!("gallery-panel obj-level-2 {}", theme)} onclick={|e: MouseEvent| e.stop_propagation()}>
+                <div class="gallery-panel-header">
+                    <h3 class="content-subttl">{t!("gallery.character_heading", name = props.name.clone())}</h3>
+                    <button class="lightbox-btn cursor-pointer" aria-label={t!("gallery.close")} onclick={close}>{"✕"}</button>
+                </div>
+                <Suspense fallback={html!(<Loading/>)}>
+                    <CharacterPicturesContent char_id={props.char_id} name={props.name.clone()} onclose={props.onclose.clone()}/>
+                </Suspense>
+            </div>
+        </div>
+    };
+
+    yew::create_portal(panel, gloo::utils::body().into())
+}
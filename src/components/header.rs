@@ -13,34 +13,7 @@ pub fn header() -> Html {
 
     let change_theme = {
         let cx = cx.clone();
-        Callback::from(move |_: MouseEvent| {
-            let cur_theme = cx.theme.clone();
-            match cur_theme {
-                Theme::Light => {
-                    cx.dispatch(cx.update_theme_into(DARK_THEME));
-                    let html_element = web_sys::window()
-                        .unwrap()
-                        .document()
-                        .unwrap()
-                        .get_elements_by_tag_name("html")
-                        .get_with_index(0)
-                        .unwrap();
-                    html_element.set_attribute("class", "dark").unwrap();
-                }
-                Theme::Dark => {
-                    cx.dispatch(cx.update_theme_into(LIGHT_THEME));
-                    let html_element = web_sys::window()
-                        .unwrap()
-                        .document()
-                        .unwrap()
-                        .get_elements_by_tag_name("html")
-                        .get_with_index(0)
-                        .unwrap();
-                    html_element.set_attribute("class", "light").unwrap();
-                }
-                _ => {}
-            };
-        })
+        Callback::from(move |_: MouseEvent| cycle_theme(&cx))
     };
 
     let change_language = {
@@ -49,7 +22,7 @@ pub fn header() -> Html {
             let cur_lang = cx.language.clone();
             match cur_lang {
                 Language::EN => {
-                    cx.dispatch(cx.update_language_into(Language::JP));
+                    cx.dispatch(cx.update_language_into(Language::JP).into());
                     let html_element = web_sys::window()
                         .unwrap()
                         .document()
@@ -60,7 +33,7 @@ pub fn header() -> Html {
                     html_element.set_attribute("lang", "ja").unwrap();
                 }
                 Language::JP => {
-                    cx.dispatch(cx.update_language_into(Language::EN));
+                    cx.dispatch(cx.update_language_into(Language::EN).into());
                     let html_element = web_sys::window()
                         .unwrap()
                         .document()
@@ -95,24 +68,11 @@ pub fn header() -> Html {
     //         nav.push(&Route::Debug);
     //     })
     // };
-    let time = use_state(|| "".to_string());
-
-    {
-        let time = time.clone();
-        use_effect_with((), move |_| {
-            let cur_time = Utc::now();
-            let year = format!("{}", cur_time.format("%Y"));
-            let season = season_from_month(&format!("{}", cur_time.format("%m")));
-            time.set(format!("{} {}", season, year));
-        });
-    }
-
     let go_to_cur_season = {
         let nav = nav.clone();
-        let time = time.clone();
         Callback::from(move |_: MouseEvent| {
             nav.push(&Route::AnimeResultStd {
-                content_title: format!("{}'s Selection", &(*time)),
+                content_title: seasonal_title(&Utc::now()),
                 page: 1,
                 url: SEASONAL,
             });
@@ -146,7 +106,7 @@ pub fn header() -> Html {
                 cx.dispatch(
                     (*cx)
                         .update_query_into((*query).clone())
-                        .update_page_into(1),
+                        .update_page_into(1).into(),
                 );
                 web_sys::window()
                     .expect("Missing window.")
@@ -104,7 +104,7 @@ fn content() -> HtmlResult {
                         (*cx)
                             .cache
                             .update_home_page_result(Some((s.clone(), t.clone()))),
-                    ),
+                    ).into(),
                 );
                 (Ok(s), Ok(t))
             } else {
@@ -116,24 +116,14 @@ fn content() -> HtmlResult {
         }
     })?;
 
-    let time = use_state(|| "".to_string());
-
-    {
-        let time = time.clone();
-        use_effect_with((), move |_| {
-            let cur_time = Utc::now();
-            let year = format!("{}", cur_time.format("%Y"));
-            let season = season_from_month(&format!("{}", cur_time.format("%m")));
-            time.set(format!("{} {}", season, year));
-        });
-    }
+    let season_title = seasonal_title(&Utc::now());
 
     let go_to_cur_season = {
-        let time = time.clone();
+        let season_title = season_title.clone();
         let nav = nav.clone();
         Callback::from(move |_: MouseEvent| {
             nav.push(&Route::AnimeResultStd {
-                content_title: format!("{}'s Selection", &(*time)),
+                content_title: season_title.clone(),
                 page: 1,
                 url: SEASONAL,
             });
@@ -144,7 +134,7 @@ fn content() -> HtmlResult {
         let nav = nav.clone();
         Callback::from(move |_: MouseEvent| {
             nav.push(&Route::AnimeResultStd {
-                content_title: "Anime of All Time".to_string(),
+                content_title: t!("home.top"),
                 page: 1,
                 url: TOP,
             });
@@ -190,7 +180,7 @@ fn content() -> HtmlResult {
             <div class={format!("seasonal-wrapper obj-level-1")}>
                 <div class="header-home">
                     <a id="l-arrow-s" class={format!("a-btn-bg cursor-pointer nostretch ripple {} obj-level-2", theme)} onclick={move_left_seasonal}><img class={format!("cursor-pointer icon-{} header-arrow", theme)} src="./static/left-arrow.png" width="20px"/></a>
-                    <a class="cursor-pointer" onclick={go_to_cur_season.clone()}><h2 class="cursor-pointer content-ttl glow-onhover u_onhover">{format!("{}'s Selection", &(*time))}</h2></a>
+                    <a class="cursor-pointer" onclick={go_to_cur_season.clone()}><h2 class="cursor-pointer content-ttl glow-onhover u_onhover">{&season_title}</h2></a>
                     <a class="cursor-pointer" onclick={go_to_cur_season}><img class={format!("cursor-pointer icon-{}", theme)} src="./static/external.png" id="goto" width="24px"/></a>
                     <a id="r-arrow-s" class={format!("a-btn-bg cursor-pointer nostretch ripple {} obj-level-2", theme)} onclick={move_right_seasonal}><img class={format!("cursor-pointer icon-{} header-arrow", theme)} src="./static/right-arrow.png" width="20px"/></a>
                 </div>
@@ -206,7 +196,7 @@ fn content() -> HtmlResult {
             <div class={format!("top-wrapper obj-level-1")}>
                 <div class="header-home">
                     <a id="l-arrow-t" class={format!("a-btn-bg cursor-pointer nostretch ripple {} obj-level-2", theme)} onclick={move_left_top}><img class={format!("cursor-pointer icon-{} header-arrow", theme)} src="./static/left-arrow.png" width="20px"/></a>    
-                    <a class="cursor-pointer" onclick={go_top_anime.clone()}><h2 class="cursor-pointer content-ttl glow-onhover u_onhover">{"Anime of All Time"}</h2></a>
+                    <a class="cursor-pointer" onclick={go_top_anime.clone()}><h2 class="cursor-pointer content-ttl glow-onhover u_onhover">{t!("home.top")}</h2></a>
                     <a class="cursor-pointer" onclick={go_top_anime}><img class={format!("cursor-pointer icon-{}", theme)} src="./static/external.png" id="goto" width="24px"/></a>
                     <a id="r-arrow-t" class={format!("a-btn-bg cursor-pointer nostretch ripple {} obj-level-2", theme)} onclick={move_right_top}><img class={format!("cursor-pointer icon-{} header-arrow", theme)} src="./static/right-arrow.png" width="20px"/></a>            
                 </div>
@@ -219,6 +209,8 @@ fn content() -> HtmlResult {
                 </div>
             </div>
 
+            <ForYou/>
+
             </div>
             </>
             }
@@ -234,7 +226,7 @@ fn content() -> HtmlResult {
 #[function_component(Home)]
 pub fn test_component() -> Html {
     let fallback = html! {<Loading/>};
-    use_title("Home | ANiNFO".to_string());
+    use_title(format!("{} | ANiNFO", t!("home.page_title")));
     let cx = use_context::<AppContext>().unwrap();
 
     html! {
@@ -0,0 +1,195 @@
+use crate::prelude::*;
+use wasm_bindgen::closure::Closure;
+use web_sys::{js_sys, Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct InfiniteAnimeGridProp {
+    /// Identifies the list, e.g. the search query. Coming back to a list with the
+    /// same key restores the cards loaded so far and the scroll position.
+    pub list_key: String,
+    /// Request URL without the `page` parameter.
+    pub base_link: String,
+    pub first_page: QueryResult,
+    /// Search query the pages are ranked against, as `first_page` already was.
+    #[prop_or_default]
+    pub rank_query: Option<String>,
+}
+
+#[function_component]
+pub fn InfiniteAnimeGrid(props: &InfiniteAnimeGridProp) -> Html {
+    let (state, dispatch) = use_store::<InfiniteScrollState>();
+    let loading = use_state(|| false);
+    let failed = use_state(|| false);
+    let sentinel = use_node_ref();
+
+    {
+        let dispatch = dispatch.clone();
+        let props = props.clone();
+        use_effect_with(props.list_key.clone(), move |_| {
+            let saved = dispatch.get();
+            if saved.list_key == props.list_key {
+                let y = saved.scroll_y;
+                // Give the restored cards a moment to be laid out before jumping back
+                gloo::timers::callback::Timeout::new(50, move || {
+                    web_sys::window()
+                        .expect("Missing window.")
+                        .scroll_to_with_x_and_y(0f64, y);
+                })
+                .forget();
+            } else {
+                let pagination = &props.first_page.pagination;
+                dispatch.set(InfiniteScrollState {
+                    list_key: props.list_key.clone(),
+                    items: props.first_page.data.clone(),
+                    next_page: if pagination.has_next_page {
+                        Some(pagination.current_page + 1)
+                    } else {
+                        None
+                    },
+                    scroll_y: 0f64,
+                });
+            }
+
+            // Runs when leaving the list, e.g. when a card opens AnimeDetails
+            move || {
+                let y = web_sys::window()
+                    .expect("Missing window.")
+                    .scroll_y()
+                    .unwrap_or(0f64);
+                dispatch.reduce_mut(|s| s.scroll_y = y);
+            }
+        });
+    }
+
+    let load_next = {
+        let dispatch = dispatch.clone();
+        let loading = loading.clone();
+        let failed = failed.clone();
+        let props = props.clone();
+
+        Callback::from(move |_: ()| {
+            let cur = dispatch.get();
+            let next = match (cur.list_key == props.list_key, cur.next_page) {
+                (true, Some(n)) => n,
+                _ => return,
+            };
+
+            loading.set(true);
+            let dispatch = dispatch.clone();
+            let loading = loading.clone();
+            let failed = failed.clone();
+            let props = props.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let link = format!("{}&page={}", props.base_link, next);
+                match force_req::<QueryResult>(&link).await {
+                    Ok(r) => dispatch.reduce_mut(|s| {
+                        if s.list_key != props.list_key {
+                            return;
+                        }
+                        // Jikan's pages can overlap when its index updates mid-browse
+                        let mut fresh = r
+                            .data
+                            .into_iter()
+                            .filter(|ao| !s.items.iter().any(|i| i.mal_id == ao.mal_id))
+                            .collect::<Vec<AnimeObj>>();
+                        // Only within the new page, so cards already seen stay put
+                        if let Some(q) = props.rank_query.as_ref() {
+                            rank_by_titles(q, &mut fresh);
+                        }
+                        s.items.extend(fresh);
+                        s.next_page = if r.pagination.has_next_page {
+                            Some(next + 1)
+                        } else {
+                            None
+                        };
+                    }),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        failed.set(true);
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let sentinel = sentinel.clone();
+        use_effect_with(
+            (
+                state.next_page,
+                *loading,
+                *failed,
+                state.list_key == props.list_key,
+            ),
+            move |(next_page, is_loading, has_failed, is_current)| {
+                let mut observer: Option<(IntersectionObserver, Closure<dyn FnMut(js_sys::Array)>)> =
+                    None;
+
+                if next_page.is_some() && !*is_loading && !*has_failed && *is_current {
+                    let c = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                        let visible = entries.iter().any(|e| {
+                            e.unchecked_into::<IntersectionObserverEntry>()
+                                .is_intersecting()
+                        });
+                        if visible {
+                            load_next.emit(());
+                        }
+                    });
+
+                    // Start fetching a bit before the sentinel actually scrolls into view
+                    let mut init = IntersectionObserverInit::new();
+                    init.root_margin("600px");
+
+                    if let (Some(elem), Ok(obs)) = (
+                        sentinel.cast::<Element>(),
+                        IntersectionObserver::new_with_options(c.as_ref().unchecked_ref(), &init),
+                    ) {
+                        obs.observe(&elem);
+                        observer = Some((obs, c));
+                    }
+                }
+
+                move || {
+                    if let Some((obs, _c)) = observer {
+                        obs.disconnect();
+                    }
+                }
+            },
+        );
+    }
+
+    let items = if state.list_key == props.list_key {
+        state.items.clone()
+    } else {
+        props.first_page.data.clone()
+    };
+
+    let retry = {
+        let failed = failed.clone();
+        Callback::from(move |_: MouseEvent| failed.set(false))
+    };
+
+    html! {
+        <>
+            <div class="cards-wrapper">
+                {into_anime_cards(&items)}
+            </div>
+            <div class="infinite-scroll-sentinel" ref={sentinel}>
+            {
+                if *loading {
+                    html!{<Loading/>}
+                } else if *failed {
+                    html!{
+                        <a class="a-btn u_onhover" onclick={retry}>{t!("infinite.load_failed")}</a>
+                    }
+                } else if state.list_key == props.list_key && state.next_page.is_none() {
+                    html!{<span class="no-result">{t!("infinite.end")}</span>}
+                } else {
+                    html!{}
+                }
+            }
+            </div>
+        </>
+    }
+}
@@ -3,7 +3,22 @@ use std::collections::HashMap;
 use gloo::dialogs::alert;
 use web_sys::{HtmlDocument, HtmlInputElement};
 
-use crate::{prelude::*, remove_cookie, set_cookie};
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct UsernameAvailability {
+    available: bool,
+}
+
+/// Result of the availability check, tagged with the username it was made for so
+/// that a slow response never labels a name the user has since changed.
+#[derive(PartialEq, Clone, Debug)]
+enum UsernameCheck {
+    Idle,
+    Checking(String),
+    Available(String),
+    Taken(String),
+}
 
 #[function_component(LeftNavbar)]
 pub fn left_navbar() -> Html {
@@ -17,34 +32,7 @@ pub fn left_navbar() -> Html {
     
     let change_theme = {
         let cx = cx.clone();
-        Callback::from(move |_: MouseEvent| {
-            let cur_theme = cx.theme.clone();
-            match cur_theme {
-                Theme::Light => {
-                    cx.dispatch(cx.update_theme_into(DARK_THEME));
-                    let html_element = web_sys::window()
-                        .unwrap()
-                        .document()
-                        .unwrap()
-                        .get_elements_by_tag_name("html")
-                        .get_with_index(0)
-                        .unwrap();
-                    html_element.set_attribute("class", "dark").unwrap();
-                }
-                Theme::Dark => {
-                    cx.dispatch(cx.update_theme_into(LIGHT_THEME));
-                    let html_element = web_sys::window()
-                        .unwrap()
-                        .document()
-                        .unwrap()
-                        .get_elements_by_tag_name("html")
-                        .get_with_index(0)
-                        .unwrap();
-                    html_element.set_attribute("class", "light").unwrap();
-                }
-                _ => {}
-            };
-        })
+        Callback::from(move |_: MouseEvent| cycle_theme(&cx))
     };
 
     let change_language = {
@@ -53,7 +41,7 @@ pub fn left_navbar() -> Html {
             let cur_lang = cx.language.clone();
             match cur_lang {
                 Language::EN => {
-                    cx.dispatch(cx.update_language_into(Language::JP));
+                    cx.dispatch(cx.update_language_into(Language::JP).into());
                     let html_element = web_sys::window()
                         .unwrap()
                         .document()
@@ -64,7 +52,7 @@ pub fn left_navbar() -> Html {
                     html_element.set_attribute("lang", "ja").unwrap();
                 }
                 Language::JP => {
-                    cx.dispatch(cx.update_language_into(Language::EN));
+                    cx.dispatch(cx.update_language_into(Language::EN).into());
                     let html_element = web_sys::window()
                         .unwrap()
                         .document()
@@ -97,33 +85,49 @@ pub fn left_navbar() -> Html {
     let form_state = use_state(|| "login".to_string());
     let password_verify = use_state(|| "".to_string());
 
-    let password_ok = use_state(|| false);
+    let register_error = use_state(|| None::<RegisterError>);
+    let username_check = use_state(|| UsernameCheck::Idle);
+
+    {
+        let username_check = username_check.clone();
+        let register_error = register_error.clone();
+        let u = (*username).clone();
+        let state = (*form_state).clone();
+        use_debounce_effect_with_deps(
+            move || {
+                let name = u.trim().to_string();
+                if *register_error == Some(RegisterError::UsernameTaken) {
+                    register_error.set(None);
+                }
+                if state != "register" || validate_username(&name).is_err() {
+                    username_check.set(UsernameCheck::Idle);
+                    return;
+                }
+
+                username_check.set(UsernameCheck::Checking(name.clone()));
+                wasm_bindgen_futures::spawn_local(async move {
+                    let link = format!("{}/api/v1/users/available/{}", BASE_URL, &name);
+                    match fetch_data_into::<UsernameAvailability>(&link).await {
+                        Ok(r) if r.available => username_check.set(UsernameCheck::Available(name)),
+                        Ok(_) => username_check.set(UsernameCheck::Taken(name)),
+                        // Not knowing is fine, the register response still catches duplicates
+                        Err(_) => username_check.set(UsernameCheck::Idle),
+                    }
+                });
+            },
+            400,
+            ((*username).clone(), (*form_state).clone()),
+        );
+    }
 
     let password_verify_oninput = {
         let password_verify = password_verify.clone();
-        let password = password.clone();
-        let password_ok = password_ok.clone();
         Callback::from(move |e: InputEvent| {
             let value = e.target_unchecked_into::<HtmlInputElement>().value();
             password_verify.set(value.clone());
         })
     };
 
-    {
-        let password = password.clone();
-        let p = password.clone();
-        let p_v = password_verify.clone();
-        let password_verify = password_verify.clone();
-        let password_ok = password_ok.clone();
-        use_effect_update_with_deps(
-            move |_| {
-                password_ok.set(verify_password(&(*&password), &(*&password_verify)));
-                || {}
-            },
-            (p, p_v),
-        )
-    }
-
     let password_wrong = use_state(|| false);
 
     let login = {
@@ -139,12 +143,12 @@ pub fn left_navbar() -> Html {
             let (username, password) = (username.clone(), password.clone());
             wasm_bindgen_futures::spawn_local(async move {
                 let link = format!("{}/api/v1/users/login", BASE_URL);
+                // The backend answers with a short-lived access token and sets the
+                // refresh token as an HttpOnly cookie, which needs credentials included
                 let result = reqwasm::http::Request::post(&link)
+                    .credentials(web_sys::RequestCredentials::Include)
                     .body(
-                        serde_json::to_string(&UserRequest {
-                            username: (*username).clone(),
-                            password_hash: sha256::digest((*password).clone()),
-                        })
+                        serde_json::to_string(&UserRequest::new(&username, &password))
                         .unwrap(),
                     )
                     .header("content-type", "application/json")
@@ -165,22 +169,10 @@ pub fn left_navbar() -> Html {
                             Some(jwt) => {
                                 log!("password correct!");
                                 password_wrong.set(false);
-                                let user = response.json::<UserForResponse>().await.unwrap();
-                                cx.dispatch(
-                                    (*cx)
-                                        .update_jwt_into(Some((&jwt[7..]).to_string()))
-                                        .update_username_into(Some(user.username))
-                                        .update_uuid_into(Some(user.uuid))
-                                        .update_fav_anime_into(Some(user.fav_anime.clone()))
-                                        .update_fav_anime_id_into(Some(
-                                            {
-                                                let mut v = user.fav_anime.iter().map(|ua_response| ua_response.anime_id).collect::<Vec<i32>>();
-                                                v.sort();
-                                                v
-                                            }
-                                        ))
-                                );
-                                set_cookie("userdata", &jwt[7..], "/", 7);
+                                match response.json::<UserForResponse>().await {
+                                    Ok(user) => cx.dispatch(session_ctx(&cx, (&jwt[7..]).to_string(), user).into()),
+                                    Err(_) => server_error.set(true),
+                                }
                             }
                         }
                     }
@@ -191,28 +183,49 @@ pub fn left_navbar() -> Html {
 
     let register = {
         let form_state = form_state.clone();
-        let (username, password) = (username.clone(), password.clone());
+        let register_error = register_error.clone();
+        let (username, password, password_verify) =
+            (username.clone(), password.clone(), password_verify.clone());
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
+            // Field-level messages already explain what is wrong
+            if validate_username(username.trim()).is_err()
+                || !verify_password(&password, &password_verify)
+            {
+                return;
+            }
+
             let form_state = form_state.clone();
+            let register_error = register_error.clone();
             let (username, password) = (username.clone(), password.clone());
             wasm_bindgen_futures::spawn_local(async move {
                 let link = format!("{}/api/v1/users/register", BASE_URL);
                 let result = reqwasm::http::Request::post(&link)
                     .body(
-                        serde_json::to_string(&UserRequest {
-                            username: (*username).clone(),
-                            password_hash: sha256::digest((*password).clone()),
-                        })
+                        serde_json::to_string(&UserRequest::new(username.trim(), &password))
                         .unwrap(),
                     )
                     .header("content-type", "application/json")
                     .send()
-                    .await
-                    .unwrap();
+                    .await;
 
-                form_state.set("login".to_string());
-                alert("Your account has been created! Please login to access all ANiNFO features.");
+                match result {
+                    Ok(response) if response.status() == 200 || response.status() == 201 => {
+                        register_error.set(None);
+                        form_state.set("login".to_string());
+                        alert(&t!("auth.account_created"));
+                    }
+                    Ok(response) => {
+                        let body = response.text().await.unwrap_or_default();
+                        register_error.set(Some(RegisterError::from_response(response.status(), body)));
+                    }
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        register_error.set(Some(RegisterError::Server(
+                            t!("auth.register_server_error"),
+                        )));
+                    }
+                }
             });
         })
     };
@@ -223,47 +236,56 @@ pub fn left_navbar() -> Html {
             e.prevent_default();
             let cx = cx.clone();
             wasm_bindgen_futures::spawn_local(async move {
-                let link = format!("{}/api/v1/users/logout", BASE_URL);
-                let auth_header = format!("Bearer {}", (*cx).jwt.as_ref().unwrap());
-                let result = reqwasm::http::Request::post(&link)
-                    .header("content-type", "application/json")
-                    .header("authorization", &auth_header)
-                    .send()
-                    .await
-                    .unwrap();
-
-                cx.dispatch(
-                    (*cx)
-                        .update_jwt_into(None)
-                        .update_username_into(None)
-                        .update_uuid_into(None)
-                        .update_fav_anime_into(None)
-                        .update_fav_anime_id_into(None),
-                );
-                remove_cookie("userdata");
+                end_session(&cx).await;
             });
         })
     };
 
-    let handle_nsfw = {
-        let cx = cx.clone();
+    let go_to_account = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::Account))
+    };
 
-        Callback::from(move |_: MouseEvent| {
-            // log!("NSFW toggled.");
-            // e.prevent_default();
-            let msg = r#"
-Attention for younger audiences
+    let go_to_stats = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::Stats))
+    };
 
-By checking the "Allow NSFW results" option, you acknowledge that anime search results may include content unsuitable for younger viewers. This includes themes, images, or depictions that some may find inappropriate or offensive.
+    let go_to_feed = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::Feed))
+    };
+
+    let go_to_my_list = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::MyList))
+    };
 
-Please be aware that accessing such content may be illegal in certain countries or jurisdictions. Users are solely responsible for any legal repercussions that may arise from their decision to continue with this option enabled. Kindly exercise caution regarding the viewing of such content in various settings.
+    let handle_scroll_mode = {
+        let cx = cx.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let mode = match (*cx).scroll_mode {
+                ScrollMode::Paged => ScrollMode::Infinite,
+                ScrollMode::Infinite => ScrollMode::Paged,
+            };
+            cx.dispatch((*cx).update_scroll_mode_into(mode).into());
+        })
+    };
 
-This feature is strictly intended for users 18 years of age and older. 
+    let move_title_up = {
+        let cx = cx.clone();
+        Callback::from(move |kind: TitleKind| {
+            cx.dispatch((*cx).update_title_order_into(move_title_kind_up(&(*cx).title_order, kind)).into());
+        })
+    };
 
-For a safer browsing experience, consider keeping the "Allow NSFW results" option unchecked.
+    let handle_nsfw = {
+        let cx = cx.clone();
 
-*NOTE: All images are retrieved from MyAnimeList's CDN (Content Delivery Network), and all images are censored.
-            "#;
+        Callback::from(move |_: MouseEvent| {
+            // log!("NSFW toggled.");
+            // e.prevent_default();
 
             let checkbox = web_sys::window()
                 .unwrap()
@@ -276,13 +298,13 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
             if !(*cx).nsfw {
                 let user_ok = web_sys::window()
                     .unwrap()
-                    .confirm_with_message(msg)
+                    .confirm_with_message(&t!("nav.nsfw_warning"))
                     .unwrap();
 
                 if user_ok {
                     checkbox.set_checked(true);
                     checkbox.set_attribute("checked", "true").unwrap();
-                    cx.dispatch((*cx).update_nsfw_into(true));
+                    cx.dispatch((*cx).update_nsfw_into(true).into());
                 } else {
                     checkbox.set_checked(false);
                     checkbox
@@ -294,7 +316,7 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                 checkbox
                     .remove_attribute("checked")
                     .expect("Cannot modify checkbox.");
-                cx.dispatch((*cx).update_nsfw_into(false));
+                cx.dispatch((*cx).update_nsfw_into(false).into());
             }
         })
     };
@@ -361,6 +383,7 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
         })
     };
 
+    // Catalog key, so the greeting follows language changes
     let cur_greeting = use_state(|| "".to_string());
 
     {
@@ -368,13 +391,13 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
         use_effect_once(move || {
             let time_now = chrono::Local::now().hour();
             if time_now < 12 {
-                cur_greeting.set("Good morning".to_string())
+                cur_greeting.set("nav.greeting_morning".to_string())
             } else if time_now < 17 {
-                cur_greeting.set("Good afternoon".to_string())
+                cur_greeting.set("nav.greeting_afternoon".to_string())
             } else if time_now < 21 {
-                cur_greeting.set("Good evening".to_string())
+                cur_greeting.set("nav.greeting_evening".to_string())
             } else {
-                cur_greeting.set("Good night".to_string())
+                cur_greeting.set("nav.greeting_night".to_string())
             }
 
             || {}
@@ -417,8 +440,8 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                             html!{
                             <>
                             <div class="nb-item">
-                            <h3>{&*cur_greeting}{"!"}</h3>
-                            {"You are not logged in."}
+                            <h3>{t!(&*cur_greeting)}{"!"}</h3>
+                            {t!("nav.not_logged_in")}
                             </div>
 
                             {match &(*form_state).as_str() {
@@ -426,14 +449,14 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                                         <>
                                     <div class="nb-item">
                                         <form id="login-form" onsubmit={login} method="post">
-                                            <input class={format!("nb-query {}", theme)} type="text" oninput={username_oninput} placeholder="Username"/>
+                                            <input class={format!("nb-query {}", theme)} type="text" oninput={username_oninput} placeholder={t!("auth.username_placeholder")}/>
                                             {
                                                 match *password_shown {
                                                     true => {
-                                                        html!{<input id="password" class={format!("nb-query {}", theme)} type="text" oninput={password_oninput} placeholder="Password"/>}
+                                                        html!{<input id="password" class={format!("nb-query {}", theme)} type="text" oninput={password_oninput} placeholder={t!("auth.password_placeholder")}/>}
                                                     },
                                                     false => {
-                                                        html!{<input id="password" class={format!("nb-query {}", theme)} type="password" oninput={password_oninput} placeholder="Password"/>}
+                                                        html!{<input id="password" class={format!("nb-query {}", theme)} type="password" oninput={password_oninput} placeholder={t!("auth.password_placeholder")}/>}
                                                     }
                                                 }
                                             }
@@ -445,20 +468,20 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                                     <div class="nb-item">
                                     <label class="basic-checkbox-label" for="show-password">
                                         <input id="show-password" class="basic-checkbox" type="checkbox" onclick={show_password}/>
-                                        <span class="basic-checkbox-text">{"Show password"}</span>
+                                        <span class="basic-checkbox-text">{t!("nav.show_password")}</span>
                                     </label>
                                     </div>
 
                                     {match (*password_wrong) {
                                         true => {html!{
-                                            <span class="warning-plaintext">{"Username and/or password are invalid. Please check your credentials again."}</span>
+                                            <span class="warning-plaintext">{t!("auth.invalid_credentials")}</span>
                                         }},
                                         false => {html!{}}
                                     }}
 
                                     {match (*server_error) {
                                         true => {html!{
-                                            <span class="warning-plaintext">{"Error connecting to the server. Please try logging in again."}</span>
+                                            <span class="warning-plaintext">{t!("auth.login_server_error")}</span>
                                         }},
                                         false => {html!{}}
                                     }}
@@ -466,7 +489,7 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                                     <div class={format!("nb-item nb-max-width")}>
                                         <label for="login">
                                         <a class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", &theme)} type="submit" form="login-form">
-                                            {"Login"}
+                                            {t!("nav.login")}
                                             // <img id="theme-toggle-icon" class={format!("icon-{}", theme)} src="./static/search.svg" width="28px" height="28px" />
                                         </a>
                                         </label>
@@ -474,9 +497,9 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
 
 
                                     <div id="open-register-button" class="nb-item">
-                                        {"Don't have an account yet? "}
+                                        {t!("nav.no_account")}
                                         <a class="nb-btn cursor-pointer u_onhover" onclick={register_open}>
-                                            {"Register!"}
+                                            {t!("nav.register_link")}
                                             // <img id="theme-toggle-icon" class={format!("icon-{}", theme)} src="./static/search.svg" width="28px" height="28px" />
                                         </a>
                                     </div>
@@ -486,22 +509,41 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                                     <>
                                     <div class="nb-item">
                                         <form id="registration-form" onsubmit={register} method="post">
-                                            <input class={format!("nb-query {}", theme)} type="text" oninput={username_oninput} placeholder="Username"/>
+                                            <input class={format!("nb-query {}", theme)} type="text" oninput={username_oninput} placeholder={t!("auth.username_placeholder")}/>
+                                            {
+                                                match (validate_username((*username).trim()), &*username_check, &*register_error) {
+                                                    _ if (*username).is_empty() => html!{},
+                                                    (Err(msg), _, _) => html!{<span class="warning-plaintext field-msg">{msg}</span>},
+                                                    (_, _, Some(RegisterError::UsernameTaken)) => html!{
+                                                        <span class="warning-plaintext field-msg">{t!("auth.username_taken")}</span>
+                                                    },
+                                                    (_, UsernameCheck::Checking(n), _) if n == (*username).trim() => html!{
+                                                        <span class="field-msg">{t!("auth.username_checking")}</span>
+                                                    },
+                                                    (_, UsernameCheck::Available(n), _) if n == (*username).trim() => html!{
+                                                        <span class="success-plaintext field-msg">{t!("auth.username_available")}</span>
+                                                    },
+                                                    (_, UsernameCheck::Taken(n), _) if n == (*username).trim() => html!{
+                                                        <span class="warning-plaintext field-msg">{t!("auth.username_taken")}</span>
+                                                    },
+                                                    _ => html!{},
+                                                }
+                                            }
                                             {
                                                 match *password_shown {
                                                     true => {
                                                         html!{
                                                             <>
-                                                            <input id="password" class={format!("nb-query {}", theme)} type="text" oninput={password_oninput} placeholder="Password"/>
-                                                            <input id="password_verify" class={format!("nb-query {}", theme)} type="text" oninput={password_verify_oninput} placeholder="Verify password"/>
+                                                            <input id="password" class={format!("nb-query {}", theme)} type="text" oninput={password_oninput} placeholder={t!("auth.password_placeholder")}/>
+                                                            <input id="password_verify" class={format!("nb-query {}", theme)} type="text" oninput={password_verify_oninput} placeholder={t!("auth.password_verify_placeholder")}/>
                                                             </>
                                                         }
                                                     },
                                                     false => {
                                                         html!{
                                                             <>
-                                                            <input id="password" class={format!("nb-query {}", theme)} type="password" oninput={password_oninput} placeholder="Password"/>
-                                                            <input id="password_verify" class={format!("nb-query {}", theme)} type="password" oninput={password_verify_oninput} placeholder="Verify password"/>
+                                                            <input id="password" class={format!("nb-query {}", theme)} type="password" oninput={password_oninput} placeholder={t!("auth.password_placeholder")}/>
+                                                            <input id="password_verify" class={format!("nb-query {}", theme)} type="password" oninput={password_verify_oninput} placeholder={t!("auth.password_verify_placeholder")}/>
                                                             </>
                                                         }
                                                     }
@@ -514,32 +556,48 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                                     <div class="nb-item">
                                     <label class="basic-checkbox-label" for="show-password">
                                         <input id="show-password" class="basic-checkbox" type="checkbox" onclick={show_password_register}/>
-                                        <span class="basic-checkbox-text">{"Show password"}</span>
+                                        <span class="basic-checkbox-text">{t!("nav.show_password")}</span>
                                     </label>
                                     </div>
 
                                     <div class="nb-item">
-                                        <span><b>{"Password Guidelines"}</b></span>
-                                        <ol>
-                                            <li>{"Password must at least be 8 characters long."}</li>
-                                            <li>{"Password must contain at least one of the following: uppercase letter, lowercase letter, symbol/punctuation, and number."}</li>
-                                            <li>{"Password must consist of latin characters only."}</li>
-                                        </ol>
+                                        <span><b>{t!("auth.password_guidelines")}</b></span>
+                                        <ul class="password-rules">
+                                        {
+                                            PasswordRule::ALL.iter().map(|rule| {
+                                                let met = rule.is_met(&password);
+                                                html!{
+                                                    <li class={if met { "success-plaintext" } else if (*password).is_empty() { "" } else { "warning-plaintext" }}>
+                                                        {if met { "✓ " } else { "✗ " }}{rule.description()}
+                                                    </li>
+                                                }
+                                            }).collect::<Html>()
+                                        }
+                                        </ul>
                                     </div>
 
                                     <div class="nb-item">
-                                        {match *password_ok {
-                                            true => html! {},
-                                            false => html!{
-                                                <span class="warning-plaintext">{"Passwords do not match. Please check the passwords you entered again."}</span>
+                                        {
+                                            if !(*password_verify).is_empty() && *password != *password_verify {
+                                                html!{<span class="warning-plaintext">{t!("auth.password_mismatch")}</span>}
+                                            } else {
+                                                html!{}
                                             }
-                                        }}
+                                        }
+                                        {
+                                            match &*register_error {
+                                                Some(RegisterError::InvalidUsername(msg)) | Some(RegisterError::WeakPassword(msg)) | Some(RegisterError::Server(msg)) => html!{
+                                                    <span class="warning-plaintext">{msg}</span>
+                                                },
+                                                _ => html!{},
+                                            }
+                                        }
                                     </div>
 
                                     <div class={format!("nb-item nb-max-width")}>
                                         <label for="register">
                                         <a class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", &theme)} type="submit" form="registration-form">
-                                            {"Register"}
+                                            {t!("nav.register")}
                                             // <img id="theme-toggle-icon" class={format!("icon-{}", theme)} src="./static/search.svg" width="28px" height="28px" />
                                         </a>
                                         </label>
@@ -547,9 +605,9 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
 
 
                                     <div id="open-register-button" class="nb-item">
-                                        {"Already have an account? "}
+                                        {t!("nav.have_account")}
                                         <a class="nb-btn cursor-pointer u_onhover" onclick={login_open}>
-                                            {"Login!"}
+                                            {t!("nav.login_link")}
                                             // <img id="theme-toggle-icon" class={format!("icon-{}", theme)} src="./static/search.svg" width="28px" height="28px" />
                                         </a>
                                     </div>
@@ -564,12 +622,24 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
                             html!{
                                 <>
                                 <div class="nb-item">
-                                <h3>{&*cur_greeting}<b>{", "}{((cx.username).as_ref().unwrap())}</b>{"!"}</h3>
+                                <h3>{t!(&*cur_greeting)}<b>{", "}{((cx.username).as_ref().unwrap())}</b>{"!"}</h3>
+                                </div>
+
+                                <div class="nb-item">
+                                    <a class="a-btn u_onhover" onclick={go_to_stats}>{t!("nav.my_stats")}</a>
+                                </div>
+
+                                <div class="nb-item">
+                                    <a class="a-btn u_onhover" onclick={go_to_feed}>{t!("nav.feed")}</a>
+                                </div>
+
+                                <div class="nb-item">
+                                    <a class="a-btn u_onhover" onclick={go_to_account}>{t!("nav.account")}</a>
                                 </div>
 
                                 <div class={format!("nb-item nb-max-width")}>
                                 <a class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", &theme)} onclick={logout}>
-                                    {"Logout"}
+                                    {t!("nav.logout")}
                                     // <img id="theme-toggle-icon" class={format!("icon-{}", theme)} src="./static/search.svg" width="28px" height="28px" />
                                 </a>
                             </div>
@@ -581,13 +651,50 @@ For a safer browsing experience, consider keeping the "Allow NSFW results" optio
 
                 <div class="nb-item">
                 <label class="basic-checkbox-label" for="nsfw-tog">
-                    <input class="basic-checkbox" type="checkbox" id="nsfw-tog" onclick={handle_nsfw}/>
-                    <span class="basic-checkbox-text">{"Allow NSFW results"}</span>
+                    <input class="basic-checkbox" type="checkbox" id="nsfw-tog" checked={(*cx).nsfw} onclick={handle_nsfw}/>
+                    <span class="basic-checkbox-text">{t!("nav.nsfw")}</span>
                 </label>
                 </div>
 
                 <div class="nb-item">
-                        <a class="a-btn u_onhover" onclick={move |_| nav.push(&Route::About)}>{"About us"}</a>
+                <label class="basic-checkbox-label" for="scroll-mode-tog">
+                    <input class="basic-checkbox" type="checkbox" id="scroll-mode-tog" checked={(*cx).scroll_mode == ScrollMode::Infinite} onclick={handle_scroll_mode}/>
+                    <span class="basic-checkbox-text">{t!("nav.infinite_scroll")}</span>
+                </label>
+                </div>
+
+                <div class="nb-item">
+                    <a class="a-btn u_onhover" onclick={go_to_my_list}>{t!("nav.my_list")}</a>
+                </div>
+
+                <div class="nb-item">
+                    <span><b>{t!("nav.title_order")}</b></span>
+                    <ol class="title-order">
+                    {
+                        (*cx).title_order.iter().enumerate().map(|(i, kind)| {
+                            let kind = *kind;
+                            let move_title_up = move_title_up.clone();
+                            html!{
+                                <li>
+                                    <span>{t!(kind.label_key())}</span>
+                                    {
+                                        if i > 0 {
+                                            html!{
+                                                <a class="a-btn u_onhover cursor-pointer" title={t!("nav.move_up")} onclick={move |_| move_title_up.emit(kind)}>{"↑"}</a>
+                                            }
+                                        } else {
+                                            html!{}
+                                        }
+                                    }
+                                </li>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </ol>
+                </div>
+
+                <div class="nb-item">
+                        <a class="a-btn u_onhover" onclick={move |_| nav.push(&Route::About)}>{t!("nav.about")}</a>
                 </div>
 
             </div>
@@ -1,6 +1,3 @@
-use std::collections::HashMap;
-
-use web_sys::HtmlDocument;
 use yew::prelude::*;
 use crate::prelude::*;
 
@@ -16,80 +13,84 @@ pub struct LoginWrapperProps {
 pub fn login_wrapper(props: &LoginWrapperProps) -> Html {
     let cx = use_context::<AppContext>().unwrap();
 
+    // Restore the session from the refresh cookie once per page load
     {
         let cx = cx.clone();
-        use_effect_with(((*cx).jwt.clone(), (*cx).username.clone(), (*cx).uuid.clone()), move |_| {
-            let closure_to_return = || {};
-            let cx = cx.clone();
-            // Check if user data is present, and if user is authorized
-            if (*cx).jwt.is_some() && (*cx).username.is_some() && (*cx).uuid.is_some() {
-                return closure_to_return;
-            }
-            let document = gloo::utils::document().unchecked_into::<HtmlDocument>();
-            let cookie = document.cookie().unwrap_or_default();
-            let cookies = cookie
-                .split(';')
-                .map(|s| {
-                    let mut cookie = s.trim().split('=');
-                    let key = cookie.next().unwrap_or_default().trim();
-                    let value = cookie.next().unwrap_or_default().trim();
-                    (key, value)
-                } )
-                .collect::<HashMap<&str, &str>>();
-            log!(&format!("login-wrapper: Cookies found on device -> {:#?}", cookies));
+        use_effect_once(move || {
+            // Tokens used to be kept in a JS-readable cookie; make sure none is left behind
+            remove_cookie("userdata");
 
-            match cookies.get("userdata") {
-                None => { // Cookie not found
-                    log!("login-wrapper: No JWT cookie found.");
-                },
-                Some(jwt) => { // User cookie is found, check if cookie is valid
-                    log!("login-wrapper: JWT cookie found: ", *jwt);
-                    cx.update_jwt_into(Some(jwt.to_string()));
+            wasm_bindgen_futures::spawn_local(async move {
+                let jwt = match refresh_session().await {
+                    Ok(jwt) => jwt,
+                    Err(_) => {
+                        log!("login-wrapper: No session to restore.");
+                        return;
+                    }
+                };
 
-                    {
-                        let cx = cx.clone();
-                        let jwt = jwt.to_string();
-                        wasm_bindgen_futures::spawn_local(async move {
-                            let link = format!("{}/api/v1/login_check", BASE_URL);
-                            let result = reqwasm::http::Request::post(&link)
-                                .header("content-type", "application/json")
-                                .header("Authorization", &format!("Bearer {}", jwt))
-                                .send()
-                                .await;
+                let link = format!("{}/api/v1/login_check", BASE_URL);
+                let result = AuthClient::with_token(&cx, jwt.clone()).post(&link).await;
 
-                            match result {
-                                Err(_) => { // Any JWT stored in cookie is invalid, remove from memory if any
-                                    cx.dispatch((*cx).update_jwt_into(None).update_username_into(None).update_uuid_into(None));
-                                    remove_cookie("userdata");
-                                },
-                                Ok(response) => { // JWT stored in cookie is valid, proceed to update user data in memory
-                                    let user = response.json::<UserForResponse>().await;
-                                    if let Ok(user) = user {
-                                        log!(&format!("Setting user info: {:?}", user));
-                                        cx.dispatch(
-                                            (*cx)
-                                                .update_jwt_into(Some(jwt.to_string()))
-                                                .update_username_into(Some(user.username))
-                                                .update_uuid_into(Some(user.uuid))
-                                                .update_fav_anime_into(Some(user.fav_anime.clone()))
-                                                .update_fav_anime_id_into(Some(
-                                                    {
-                                                        let mut v = user.fav_anime.iter().map(|ua_response| ua_response.anime_id).collect::<Vec<i32>>();
-                                                        v.sort();
-                                                        v
-                                                    }
-                                                ))
-                                        );
-                                    }
-                                    // set_cookie("userdata", &jwt[7..], "/")
-                                }
-                            }
-                        });
+                match result {
+                    Ok(response) if response.ok() => {
+                        if let Ok(user) = response.json::<UserForResponse>().await {
+                            log!(&format!("Setting user info: {:?}", user));
+                            cx.dispatch(session_ctx(&cx, jwt, user).into());
+                        }
                     }
+                    _ => clear_session(&cx),
                 }
-            };
+            });
+
+            || {}
+        });
+    }
 
-            closure_to_return
+    // Bring guest and account settings together whenever someone logs in
+    {
+        let cx = cx.clone();
+        use_effect_with((*cx).uuid, move |uuid| {
+            if uuid.is_some() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    sync_settings_on_login(&cx).await;
+                });
+            }
+            || {}
+        });
+    }
+
+    // Keep a copy of the list on the device so it can be browsed offline
+    {
+        let deps = ((*cx).username.clone(), (*cx).fav_anime.clone());
+        use_effect_with(deps, move |(username, fav_anime)| {
+            if let (Some(username), Some(fav_anime)) = (username, fav_anime) {
+                save_offline_list(username, fav_anime);
+            }
+            || {}
+        });
+    }
+
+    // Refresh the access token shortly before it expires
+    {
+        let cx = cx.clone();
+        use_effect_with((*cx).jwt.clone(), move |jwt| {
+            let timeout = jwt.as_ref().map(|jwt| {
+                gloo::timers::callback::Timeout::new(refresh_delay_ms(jwt), move || {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match refresh_session().await {
+                            Ok(new_jwt) => cx.dispatch(AppAction::SetJwt(new_jwt)),
+                            Err(e) => {
+                                log!(format!("{:?}", e));
+                                clear_session(&cx);
+                            }
+                        }
+                    });
+                })
+            });
+
+            // Dropping the timeout cancels it when the token changes or the user logs out
+            move || drop(timeout)
         });
     }
 
@@ -98,4 +99,4 @@ pub fn login_wrapper(props: &LoginWrapperProps) -> Html {
         {props.children.clone()}
         </>
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,5 @@
 pub mod about;
+pub mod account;
 pub mod anime_card;
 pub mod anime_details;
 pub mod anime_result_std;
@@ -6,21 +7,32 @@ pub mod appctx_handler;
 pub mod backup;
 pub mod character_card;
 pub mod comment_section;
+pub mod compare;
 pub mod context_debugger;
 pub mod episode_cards;
+pub mod episode_detail;
 pub mod error;
 pub mod experiment;
 pub mod explore;
+pub mod feed;
 pub mod footer;
+pub mod for_you;
+pub mod gallery;
 pub mod header;
 pub mod header_nb_wrapper;
 pub mod home;
+pub mod infinite_scroll;
 pub mod left_navbar;
 pub mod loading;
+pub mod my_list;
+pub mod offline_banner;
 pub mod page_button;
 pub mod privacy_policy;
+pub mod pwa;
 pub mod search_result;
+pub mod stats;
 pub mod test;
 pub mod torrent_popup;
 pub mod torrents;
+pub mod user_profile;
 pub mod login_wrapper;
\ No newline at end of file
@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+/// The user's list and recently viewed anime. Both are also kept on the device, so
+/// this page keeps working offline, when the session cannot be restored.
+#[function_component(MyList)]
+pub fn my_list() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    use_title(format!("{} | ANiNFO", t!("my_list.title")));
+
+    let (favs, from_device) = match (*cx).fav_anime.as_ref() {
+        Some(favs) => (Some(favs.clone()), false),
+        None => (load_offline_list().map(|l| l.fav_anime), true),
+    };
+    let recent = load_recently_viewed();
+
+    html! {
+        <div class="search-result-wrapper">
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("my_list.title")}</h2></span>
+            </div>
+            {
+                match favs {
+                    None => html! {
+                        <span class="no-result">{t!("my_list.login_required")}</span>
+                    },
+                    Some(favs) if favs.is_empty() => html! {
+                        <span class="no-result">{t!("my_list.empty")}</span>
+                    },
+                    Some(favs) => html! {
+                        <>
+                        {
+                            if from_device {
+                                html! {<p class="my-list-note">{t!("my_list.from_device")}</p>}
+                            } else {
+                                html! {}
+                            }
+                        }
+                        {into_saved_anime_cards(favs.iter().rev().map(AnimeRecObj::from).collect())}
+                        </>
+                    },
+                }
+            }
+
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("my_list.recently_viewed")}</h2></span>
+            </div>
+            {
+                if recent.is_empty() {
+                    html! {<span class="no-result">{t!("my_list.no_recent")}</span>}
+                } else {
+                    into_saved_anime_cards(recent.iter().map(AnimeRecObj::from).collect())
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,38 @@
+use gloo::events::EventListener;
+
+use crate::prelude::*;
+
+/// Strip shown at the top of the page while the browser reports no connection.
+#[function_component(OfflineBanner)]
+pub fn offline_banner() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let nav = use_navigator().unwrap();
+    let online = use_state(is_online);
+
+    {
+        let online = online.clone();
+        use_effect_once(move || {
+            let window = web_sys::window().unwrap();
+            let listeners = {
+                let (on, off) = (online.clone(), online.clone());
+                [
+                    EventListener::new(&window, "online", move |_| on.set(true)),
+                    EventListener::new(&window, "offline", move |_| off.set(false)),
+                ]
+            };
+            move || drop(listeners)
+        });
+    }
+
+    if *online {
+        return html! {};
+    }
+
+    html! {
+        <div class={format!("offline-banner obj-level-2 {}", theme)} role="status">
+            <span>{t!("offline.banner")}</span>
+            <a class="a-btn u_onhover cursor-pointer" onclick={move |_| nav.push(&Route::MyList)}>{t!("offline.open_list")}</a>
+        </div>
+    }
+}
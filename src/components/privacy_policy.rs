@@ -17,6 +17,12 @@ pub fn privacy_policy() -> Html {
 
         <p>{"However, ANiNFO utilizes Google Fonts API to enhance the visual experience of our application. When you use ANiNFO, your device may automatically send certain information to Google, including, but not limited to, your device's IP address and the date and time of your request. This information is solely used by Google to deliver the requested font resources and is subject to Google's Privacy Policy."}</p>
 
+        <h3>{"Account Data"}</h3>
+
+        <p>{"If you choose to create an account, ANiNFO stores your username, a salted hash of your password, your favourite anime and the comments you post. This data is only used to provide those features and is never shared with third parties."}</p>
+
+        <p>{"You can download all of this data, together with your settings, as a JSON file at any time from the Account page. You can also change your username or password there, or permanently delete your account along with your favourites and comments."}</p>
+
         <h3>{"Children's Privacy"}</h3>
 
         <p>{"ANiNFO does not knowingly collect any personally identifiable information from children under the age of 13. If you are a parent or guardian and you are aware that your child has provided us with personal information, please contact us so that we can take appropriate action."}</p>
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+/// Query string the installed app receives from the share sheet, see `share_target`
+/// in manifest.json.
+#[derive(Deserialize, Default, Debug)]
+struct SharedData {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    url: String,
+}
+
+/// Opens whatever was shared to the app: the anime page for a MyAnimeList link,
+/// a search for anything else.
+#[function_component(ShareTarget)]
+pub fn share_target() -> Html {
+    let nav = use_navigator().unwrap();
+    let shared = yew_router::hooks::use_location()
+        .and_then(|l| l.query::<SharedData>().ok())
+        .unwrap_or_default();
+
+    use_effect_once(move || {
+        // Most apps put the link in `text` rather than `url`
+        let fields = [&shared.url, &shared.text, &shared.title];
+        match fields.iter().find_map(|s| mal_anime_id(s)) {
+            Some(mal_id) => nav.replace(&Route::AnimeDetails { mal_id }),
+            None => match fields.iter().map(|s| s.trim()).find(|s| !s.is_empty()) {
+                Some(q) => nav.replace(&Route::SearchResultNoPage { q: q.to_string() }),
+                None => nav.replace(&Route::HomeNew),
+            },
+        }
+        || {}
+    });
+
+    html! {<Loading/>}
+}
+
+/// Target of the "Schedule" app shortcut: this season's anime.
+#[function_component(Schedule)]
+pub fn schedule() -> Html {
+    let nav = use_navigator().unwrap();
+
+    use_effect_once(move || {
+        nav.replace(&Route::AnimeResultStd {
+            content_title: seasonal_title(&Utc::now()),
+            page: 1,
+            url: SEASONAL,
+        });
+        || {}
+    });
+
+    html! {<Loading/>}
+}
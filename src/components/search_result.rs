@@ -6,14 +6,55 @@ pub struct Props {
     pub page: u32,
 }
 
+#[derive(PartialEq, Clone, Debug)]
+struct SearchOutcome {
+    result: QueryResult,
+    relaxed_query: Option<String>,
+}
+
+/// Queries Jikan for `q`, retrying once with a relaxed query when the first page
+/// comes back empty, then re-ranks the page against every alternate title.
+async fn fetch_search_result(
+    q: &str,
+    page: u32,
+    cx: &AppContext,
+) -> Result<SearchOutcome, gloo::net::Error> {
+    let link = |q: &str| {
+        format!(
+            "https://api.jikan.moe/v4/anime?q={}&page={}{}",
+            String::from(web_sys::js_sys::encode_uri_component(q)),
+            page,
+            handle_nsfw(cx)
+        )
+    };
+
+    let mut result = force_req::<QueryResult>(&link(q)).await?;
+    let mut relaxed_query = None;
+
+    if result.data.is_empty() && page == 1 {
+        if let Some(relaxed) = relax_query(q) {
+            log!(format!("No result for {}, retrying with {}", q, &relaxed));
+            result = force_req::<QueryResult>(&link(&relaxed)).await?;
+            relaxed_query = Some(relaxed);
+        }
+    }
+
+    rank_by_titles(q, &mut result.data);
+
+    Ok(SearchOutcome {
+        result,
+        relaxed_query,
+    })
+}
+
 #[function_component(Content)]
 fn content(props: &Props) -> HtmlResult {
     let cx = use_context::<AppContext>().unwrap();
     let nav = use_navigator().unwrap();
     let props = (*props).clone();
-    use_title(format!("Search: {} | ANiNFO", &(props.q)));
+    use_title(format!("{} | ANiNFO", t!("search.page_title", query = props.q.clone())));
 
-    let result: UseFutureHandle<Result<QueryResult, gloo::net::Error>> =
+    let result: UseFutureHandle<Result<SearchOutcome, gloo::net::Error>> =
         {
             let cx = cx.clone();
             let props = props.clone();
@@ -27,64 +68,41 @@ fn content(props: &Props) -> HtmlResult {
                 |_| async move {
                     let (has_changed, _) = (*cx).has_changed();
 
-                    if has_changed {
-                        let result = force_req::<QueryResult>(&format!(
-                            "https://api.jikan.moe/v4/anime?q={}&page={}{}",
-                            (props.q).clone(),
-                            &(props.page),
-                            handle_nsfw(&cx)
-                        ))
-                        .await;
-                        if let Ok(r) = result {
-                            cx.dispatch((*cx).update_cache_into(
-                                (*cx).cache.update_search_result(Some(r.clone())),
-                            ));
-                            return Ok(r);
-                        } else if let Err(e) = result {
-                            log!(format!("{:?}", e));
-                            return Err(e);
-                        } else {
-                            return Err(GlooError(format!("Unknown error.")));
-                        }
-                    } else {
-                        if let Some(r) = (*cx).cache.search_result.clone() {
-                            return Ok(r);
-                        } else {
-                            let result = force_req::<QueryResult>(&format!(
-                                "https://api.jikan.moe/v4/anime?q={}&page={}{}",
-                                (props.q).clone(),
-                                &(props.page),
-                                handle_nsfw(&cx)
-                            ))
-                            .await;
-                            if let Ok(r) = result {
-                                cx.dispatch((*cx).update_cache_into(
-                                    (*cx).cache.update_search_result(Some(r.clone())),
-                                ));
-                                return Ok(r);
-                            } else if let Err(e) = result {
-                                log!(format!("{:?}", e));
-                                return Err(e);
-                            } else {
-                                return Err(GlooError(format!("Unknown error.")));
-                            }
+                    if !has_changed {
+                        if let Some((result, relaxed_query)) = (*cx).cache.search_result.clone() {
+                            return Ok(SearchOutcome {
+                                result,
+                                relaxed_query,
+                            });
                         }
                     }
+
+                    let outcome = fetch_search_result(&(props.q), props.page, &cx).await;
+                    match outcome {
+                        Ok(ref o) => cx.dispatch((*cx).update_cache_into(
+                            (*cx).cache.update_search_result(Some((o.result.clone(), o.relaxed_query.clone()))),
+                        ).into()),
+                        Err(ref e) => log!(format!("{:?}", e)),
+                    }
+                    outcome
                     // fetch_data_into::<QueryResult>(&format!("https://api.jikan.moe/v4/anime?q={}&page={}", (props.q).clone(), &(props.page))).await
                 },
             )?
         };
 
     let html_result = match (*result).as_ref() {
-        Ok(v) => {
+        Ok(outcome) => {
+            let v = &outcome.result;
+            // Page buttons must keep paging through whatever query actually produced the results
+            let paged_q = outcome.relaxed_query.clone().unwrap_or((props.q).clone());
             if v.data.len() == 0 {
                 html! {
                     <div class="search-result-wrapper">
                     <div class="written-content">
                         <div class="content-header">
-                            <span><h2 class="content-ttl vc-text">{"Oops!"}</h2></span>
+                            <span><h2 class="content-ttl vc-text">{t!("search.oops")}</h2></span>
                         </div>
-                        <p style="text-align: center !important;">{"No anime matching your search query is found."}</p>
+                        <p style="text-align: center !important;">{t!("search.no_match")}</p>
                     </div>
                     </div>
                 }
@@ -92,33 +110,47 @@ fn content(props: &Props) -> HtmlResult {
                 html! {
                     <div class="search-result-wrapper">
                         <div class="content-header">
-                            <span><h2 class="content-ttl vc-text">{"Search Result"}</h2></span>
+                            <span><h2 class="content-ttl vc-text">{t!("search.result")}</h2></span>
                         </div>
                         {
-                            if v.pagination.last_visible_page > 1 {
+                            if let Some(relaxed) = outcome.relaxed_query.as_ref() {
                                 html!{
-                                    <div class="page-btn-wrapper">
-                                    {into_page_btns(cx.clone(), (*v).pagination.clone(), nav.clone(), (props.q).clone(), props.page)}
-                                    </div>
+                                    <p class="search-relaxed-note">{t!("search.relaxed", query = props.q.clone(), relaxed = relaxed.clone())}</p>
                                 }
                             } else {
                                 html!{}
                             }
                         }
-
-                        <div class="cards-wrapper">
-                            {into_anime_cards(&(v.data))}
-                        </div>
-
                         {
-                            if v.pagination.last_visible_page > 1 {
+                            if (*cx).scroll_mode == ScrollMode::Infinite {
+                                html!{
+                                    <InfiniteAnimeGrid
+                                        list_key={format!("search:{}:{}", &paged_q, (*cx).nsfw)}
+                                        base_link={format!("https://api.jikan.moe/v4/anime?q={}{}", String::from(web_sys::js_sys::encode_uri_component(&paged_q)), handle_nsfw(&cx))}
+                                        first_page={v.clone()}
+                                        rank_query={Some(props.q.clone())}
+                                    />
+                                }
+                            } else if v.pagination.last_visible_page > 1 {
                                 html!{
+                                    <>
                                     <div class="page-btn-wrapper">
-                                    {into_page_btns(cx.clone(), (*v).pagination.clone(), nav.clone(), (props.q).clone(), props.page)}
+                                    {into_page_btns(cx.clone(), (*v).pagination.clone(), nav.clone(), paged_q.clone(), props.page)}
                                     </div>
+                                    <div class="cards-wrapper">
+                                        {into_anime_cards(&(v.data))}
+                                    </div>
+                                    <div class="page-btn-wrapper">
+                                    {into_page_btns(cx.clone(), (*v).pagination.clone(), nav.clone(), paged_q.clone(), props.page)}
+                                    </div>
+                                    </>
                                 }
                             } else {
-                                html!{}
+                                html!{
+                                    <div class="cards-wrapper">
+                                        {into_anime_cards(&(v.data))}
+                                    </div>
+                                }
                             }
                         }
                     </div>
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+/// Distributions are cut down to this many entries so the charts stay readable.
+const TOP_N: usize = 10;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct StatsContentProp {
+    pub fav_ids: Vec<i32>,
+}
+
+/// Charts for the anime in `fav_ids`, also shown on public profiles.
+#[function_component(StatsContent)]
+pub fn stats_content(props: &StatsContentProp) -> HtmlResult {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+
+    // The fetched entries and how many failed to load. Stats are worked out when
+    // rendering, so their labels follow the current language.
+    let result: UseFutureHandle<Result<(Vec<AnimeObj>, usize), gloo::net::Error>> =
+        use_future_with(props.fav_ids.clone(), |ids| async move {
+            // The list itself only carries titles, so fetch the metadata one entry at a time
+            let mut list = Vec::new();
+            let mut last_err = None;
+            for id in ids.iter() {
+                // One missing entry should not take the whole dashboard down
+                match lookup_anime(*id).await {
+                    Ok(ao) => list.push(ao),
+                    Err(e) => {
+                        log!(format!("{:?}", e));
+                        last_err = Some(e);
+                    }
+                }
+            }
+            match last_err {
+                Some(e) if list.is_empty() => Err(e),
+                _ => {
+                    let skipped = ids.len() - list.len();
+                    Ok((list, skipped))
+                }
+            }
+        })?;
+
+    let html_result = match (*result).as_ref() {
+        Ok((list, skipped)) => {
+            let s = compute_stats(list);
+            let hours = s.total_minutes as f64 / 60.0;
+            let genres = s.genres.iter().take(TOP_N).cloned().collect::<Vec<(String, u32)>>();
+            let studios = s.studios.iter().take(TOP_N).cloned().collect::<Vec<(String, u32)>>();
+
+            html! {
+                <div class={format!("stats-wrapper {}", theme)}>
+                    <div class="stats-summary">
+                        <div class={format!("stats-card obj-level-1 {}", theme)}>
+                            <span class="stats-number">{format_number(s.total_anime as f64, 0)}</span>
+                            <span class="stats-caption">{t!("stats.anime", count = s.total_anime)}</span>
+                        </div>
+                        <div class={format!("stats-card obj-level-1 {}", theme)}>
+                            <span class="stats-number">{format_number(s.total_episodes as f64, 0)}</span>
+                            <span class="stats-caption">{t!("stats.episodes", count = s.total_episodes)}</span>
+                        </div>
+                        <div class={format!("stats-card obj-level-1 {}", theme)}>
+                            <span class="stats-number">{format_number(hours, 1)}</span>
+                            <span class="stats-caption">{t!("stats.hours")}</span>
+                        </div>
+                    </div>
+
+                    <h3 class="stats-ttl">{t!("stats.genres")}</h3>
+                    {horizontal_bar_chart(&genres)}
+
+                    <h3 class="stats-ttl">{t!("stats.studios")}</h3>
+                    {horizontal_bar_chart(&studios)}
+
+                    <h3 class="stats-ttl">{t!("stats.scores")}</h3>
+                    {column_chart(&s.scores)}
+
+                    <h3 class="stats-ttl">{t!("stats.timeline")}</h3>
+                    {column_chart(&s.timeline)}
+
+                    <p class="stats-note">{t!("stats.note")}</p>
+                    if *skipped > 0 {
+                        <p class="stats-note">{t!("stats.skipped", count = *skipped)}</p>
+                    }
+                </div>
+            }
+        }
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {<p style="text-align: center !important;">{t!("stats.unavailable")}</p>}
+        }
+    };
+
+    Ok(html_result)
+}
+
+#[function_component(Stats)]
+pub fn stats() -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    use_title(format!("{} | ANiNFO", t!("stats.title")));
+
+    let fav_ids = (*cx).fav_anime_id.clone().unwrap_or_default();
+
+    html! {
+        <div class="search-result-wrapper">
+            <div class="content-header">
+                <span><h2 class="content-ttl vc-text">{t!("stats.title")}</h2></span>
+            </div>
+            {
+                if (*cx).jwt.is_none() {
+                    html!{<p style="text-align: center !important;">{t!("stats.login_required")}</p>}
+                } else if fav_ids.is_empty() {
+                    html!{<p style="text-align: center !important;">{t!("stats.empty")}</p>}
+                } else {
+                    html!{
+                        <Suspense fallback={html!(<Loading/>)}>
+                            <StatsContent {fav_ids}/>
+                        </Suspense>
+                    }
+                }
+            }
+        </div>
+    }
+}
@@ -19,7 +19,7 @@ fn content() -> HtmlResult {
                         (*cx)
                             .cache
                             .update_home_page_result(Some((s.clone(), t.clone()))),
-                    ),
+                    ).into(),
                 );
                 (Ok(s), Ok(t))
             } else {
@@ -0,0 +1,224 @@
+use gloo::dialogs::alert;
+
+use crate::prelude::*;
+
+#[derive(Properties, PartialEq, Clone)]
+pub struct UserProfileProps {
+    pub username: String,
+}
+
+fn avatar(username: &str, url: &Option<String>) -> Html {
+    match url {
+        Some(url) => html! {<img class="profile-avatar" src={url.clone()} alt={username.to_string()}/>},
+        None => html! {<span class="profile-avatar profile-avatar-initial">{avatar_initial(username)}</span>},
+    }
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct FollowBarProps {
+    username: String,
+    follows: FollowInfo,
+}
+
+/// Follower counts and, for other logged-in users, the follow button.
+#[function_component(FollowBar)]
+fn follow_bar(props: &FollowBarProps) -> Html {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let follows = use_state(|| props.follows);
+    let pending = use_state(|| false);
+
+    let toggle = {
+        let cx = cx.clone();
+        let follows = follows.clone();
+        let pending = pending.clone();
+        let username = props.username.clone();
+        Callback::from(move |_: MouseEvent| {
+            if *pending {
+                return;
+            }
+            let before = *follows;
+            let follow = !before.is_following;
+            follows.set(apply_follow(before, follow));
+            pending.set(true);
+
+            let cx = cx.clone();
+            let follows = follows.clone();
+            let pending = pending.clone();
+            let username = username.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Err(e) = set_following(&cx, &username, follow).await {
+                    log!(format!("{:?}", e));
+                    follows.set(before);
+                    alert(&t!("follow.failed"));
+                }
+                pending.set(false);
+            });
+        })
+    };
+
+    html! {
+        <div class="follow-bar">
+            <span>{t!("follow.followers", count = follows.followers)}</span>
+            <span>{t!("follow.following", count = follows.following)}</span>
+            if cx.jwt.is_some() && !is_own_profile(&cx, &props.username) {
+                <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {} {}", theme, if follows.is_following { "selected" } else { "" })} disabled={*pending} onclick={toggle}>
+                    {if follows.is_following { t!("follow.unfollow") } else { t!("follow.follow") }}
+                </button>
+            }
+        </div>
+    }
+}
+
+fn recent_comments(theme: &str, comments: &[ProfileComment], nav: &Navigator) -> Html {
+    if comments.is_empty() {
+        return html! {<span class="no-result">{t!("profile.no_comments")}</span>};
+    }
+    comments
+        .iter()
+        .map(|c| {
+            let nav = nav.clone();
+            let mal_id = c.anime_id;
+            let open_anime = Callback::from(move |_: MouseEvent| {
+                nav.push(&Route::AnimeDetails { mal_id });
+            });
+            html! {
+                <div class={format!("comment-card obj-level-1 {}", theme)}>
+                    <a class="a-btn username cursor-pointer" onclick={open_anime}>
+                        {&c.anime_title}
+                        if let Some(n) = c.episode {
+                            <span class="episode-tag">{t!("comments.episode_tag", n = n)}</span>
+                        }
+                    </a>
+                    <span class="date">{&c.date}</span>
+                    <span class="comment">{render_markdown(&c.comment)}</span>
+                </div>
+            }
+        })
+        .collect::<Html>()
+}
+
+#[function_component(ProfileContent)]
+fn profile_content(props: &UserProfileProps) -> HtmlResult {
+    let cx = use_context::<AppContext>().unwrap();
+    let theme = handle_theme(&cx);
+    let nav = use_navigator().unwrap();
+    // Stats cost a Jikan lookup per favourite, so they only load when asked for
+    let show_stats = use_state(|| false);
+
+    let result = {
+        let cx = cx.clone();
+        use_future_with((props.username.clone(), cx.jwt.is_some()), |deps| async move {
+            fetch_profile(&cx, &deps.0).await
+        })?
+    };
+
+    let go_to_account = {
+        let nav = nav.clone();
+        Callback::from(move |_: MouseEvent| nav.push(&Route::Account))
+    };
+
+    let load_stats = {
+        let show_stats = show_stats.clone();
+        Callback::from(move |_: MouseEvent| show_stats.set(true))
+    };
+
+    let html_result = match &*result {
+        Ok(ProfileLookup::NotFound) => html! {<ErrorPage app_err={AppErr::not_found()}/>},
+        Ok(ProfileLookup::Restricted(p)) => html! {
+            <div class="profile-header">
+                {avatar(&p.username, &p.avatar)}
+                <div class="profile-info">
+                    <h2 class="content-ttl">{&p.username}</h2>
+                    <span>{
+                        match p.privacy {
+                            ProfilePrivacy::FriendsOnly => t!("profile.friends_only"),
+                            _ => t!("profile.private"),
+                        }
+                    }</span>
+                    <FollowBar key={p.username.clone()} username={p.username.clone()} follows={p.follows}/>
+                </div>
+            </div>
+        },
+        Ok(ProfileLookup::Visible(p)) => {
+            let fav_ids = p.fav_anime.iter().map(|ua| ua.anime_id).collect::<Vec<i32>>();
+            html! {
+                <>
+                <div class="profile-header">
+                    {avatar(&p.username, &p.avatar)}
+                    <div class="profile-info">
+                        <h2 class="content-ttl">{&p.username}</h2>
+                        if let Some(date) = joined_date(&p.joined) {
+                            <span>{t!("profile.joined", date = format_date(&date))}</span>
+                        }
+                        if is_own_profile(&cx, &p.username) {
+                            <span>
+                                {t!("profile.visibility", privacy = t!(p.privacy.label_key()))}
+                                {" "}
+                                <a class="a-btn u_onhover cursor-pointer" onclick={go_to_account}>{t!("profile.change_privacy")}</a>
+                            </span>
+                        }
+                        <FollowBar key={p.username.clone()} username={p.username.clone()} follows={p.follows}/>
+                    </div>
+                </div>
+
+                <div class="content-header">
+                    <span><h2 class="content-ttl vc-text">{t!("profile.favourites")}</h2></span>
+                </div>
+                {
+                    if p.fav_anime.is_empty() {
+                        html! {<span class="no-result">{t!("profile.no_favourites")}</span>}
+                    } else {
+                        into_saved_anime_cards(p.fav_anime.iter().rev().map(AnimeRecObj::from).collect())
+                    }
+                }
+
+                if !fav_ids.is_empty() {
+                    <div class="content-header">
+                        <span><h2 class="content-ttl vc-text">{t!("profile.stats")}</h2></span>
+                    </div>
+                    if *show_stats {
+                        <Suspense fallback={html!(<Loading/>)}>
+                            <StatsContent {fav_ids}/>
+                        </Suspense>
+                    } else {
+                        <div class="profile-stats-toggle">
+                            <button class={format!("card-btn hover-highlight cursor-pointer obj-level-2 {}", theme)} onclick={load_stats}>
+                                {t!("profile.show_stats", count = fav_ids.len())}
+                            </button>
+                        </div>
+                    }
+                }
+
+                <div class="content-header">
+                    <span><h2 class="content-ttl vc-text">{t!("profile.recent_comments")}</h2></span>
+                </div>
+                <div class="profile-comments">
+                    {recent_comments(&theme, &p.recent_comments, &nav)}
+                </div>
+                </>
+            }
+        }
+        Err(e) => {
+            log!(format!("{:?}", e));
+            html! {<ErrorPage app_err={AppErr::gloo_default()}/>}
+        }
+    };
+
+    Ok(html_result)
+}
+
+/// Public profile at `/user/:username`. How much of it shows depends on the user's
+/// privacy setting and, for friends-only profiles, on who is looking.
+#[function_component(UserProfile)]
+pub fn user_profile(props: &UserProfileProps) -> Html {
+    use_title(format!("{} | ANiNFO", props.username));
+
+    html! {
+        <div class="search-result-wrapper">
+            <Suspense fallback={html!(<Loading/>)}>
+                <ProfileContent username={props.username.clone()}/>
+            </Suspense>
+        </div>
+    }
+}
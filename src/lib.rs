@@ -32,40 +32,70 @@ pub mod prelude {
     pub use crate::components::appctx_handler::*;
 
     pub use crate::components::about::*;
+    pub use crate::components::account::*;
     pub use crate::components::anime_card::*;
     pub use crate::components::anime_details::*;
     pub use crate::components::anime_result_std::*;
     pub use crate::components::backup::*;
     pub use crate::components::character_card::*;
     pub use crate::components::comment_section::*;
+    pub use crate::components::compare::*;
     pub use crate::components::context_debugger::*;
     pub use crate::components::episode_cards::*;
+    pub use crate::components::episode_detail::*;
     pub use crate::components::error::*;
     pub use crate::components::experiment::*;
     pub use crate::components::explore::*;
+    pub use crate::components::feed::*;
     pub use crate::components::footer::*;
+    pub use crate::components::for_you::*;
+    pub use crate::components::gallery::*;
     pub use crate::components::header::*;
     pub use crate::components::header_nb_wrapper::*;
     pub use crate::components::home::*;
+    pub use crate::components::infinite_scroll::*;
     pub use crate::components::left_navbar::*;
     pub use crate::components::loading::*;
+    pub use crate::components::my_list::*;
+    pub use crate::components::offline_banner::*;
     pub use crate::components::page_button::*;
     pub use crate::components::privacy_policy::*;
+    pub use crate::components::pwa::*;
     pub use crate::components::search_result::*;
+    pub use crate::components::stats::*;
     pub use crate::components::test::*;
     pub use crate::components::torrent_popup::*;
     pub use crate::components::torrents::*;
+    pub use crate::components::user_profile::*;
 
     pub use crate::stores::genre_list::*;
     pub use crate::stores::*;
 
     pub use crate::routers::*;
 
-    pub(crate) use crate::utils::app_macros::get_elem_by_id;
+    pub(crate) use crate::utils::app_macros::{get_elem_by_id, t};
+    pub use crate::utils::charts::*;
+    pub use crate::utils::comments::*;
     pub use crate::utils::data_handling::*;
+    pub use crate::utils::episodes::*;
+    pub use crate::utils::follows::*;
+    pub use crate::utils::fuzzy::*;
+    pub use crate::utils::gallery::*;
     pub use crate::utils::general::*;
+    pub use crate::utils::i18n::*;
     pub use crate::utils::interface::*;
     pub use crate::utils::into_html::*;
+    pub use crate::utils::lookups::*;
+    pub use crate::utils::markdown::*;
+    pub use crate::utils::moderation::*;
+    pub use crate::utils::offline::*;
+    pub use crate::utils::profiles::*;
+    pub use crate::utils::progress::*;
+    pub use crate::utils::recommend::*;
+    pub use crate::utils::session::*;
+    pub use crate::utils::settings::*;
+    pub use crate::utils::stats::*;
+    pub use crate::utils::titles::*;
 }
 
 use std::collections::HashMap;
@@ -257,7 +287,9 @@ pub fn app() -> Html {
                             <LeftNavbar/>
                             <Header/>
                         </HNBWrapper>
+                        <OfflineBanner/>
                         <Switch<Route> render={switch}/>
+                        <CompareTray/>
                         <Footer/>
                     </BrowserRouter>
                 </LoginWrapper>
@@ -14,6 +14,8 @@ pub enum Route {
     SearchResultNoPage { q: String },
     #[at("/anime/:mal_id")]
     AnimeDetails { mal_id: u64 },
+    #[at("/anime/:mal_id/episode/:n")]
+    EpisodeDetail { mal_id: u64, n: u32 },
     #[at("/exp")]
     Experiment,
     #[at("/backup")]
@@ -34,6 +36,22 @@ pub enum Route {
     },
     #[at("/anime/explore")]
     ExploreAnime,
+    #[at("/compare")]
+    Compare,
+    #[at("/stats")]
+    Stats,
+    #[at("/account")]
+    Account,
+    #[at("/my-list")]
+    MyList,
+    #[at("/schedule")]
+    Schedule,
+    #[at("/share")]
+    Share,
+    #[at("/user/:username")]
+    UserProfile { username: String },
+    #[at("/feed")]
+    Feed,
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -47,6 +65,7 @@ pub fn switch(routes: Route) -> Html {
         Route::SearchResult { q, page } => html! {<SearchResult {q} {page}/>},
         Route::SearchResultNoPage { q } => html! {<SearchResult {q} page=1/>},
         Route::AnimeDetails { mal_id } => html! {<AnimeDetails {mal_id}/>},
+        Route::EpisodeDetail { mal_id, n } => html! {<EpisodeDetail {mal_id} {n}/>},
         Route::Experiment => html! {<Experiment/>},
         Route::Backup => html! {<BackupComponent/>},
         Route::Debug => html! {<ContextDebugger/>},
@@ -60,5 +79,13 @@ pub fn switch(routes: Route) -> Html {
         } => html! {<AnimeResultStd {url} {content_title} {page}/>},
         Route::NotFound => html! {<ErrorPage app_err={AppErr::not_found()}/>},
         Route::ExploreAnime => html! {<ExploreAnime/>},
+        Route::Compare => html! {<CompareAnime/>},
+        Route::Stats => html! {<Stats/>},
+        Route::Account => html! {<AccountSettings/>},
+        Route::MyList => html! {<MyList/>},
+        Route::Schedule => html! {<Schedule/>},
+        Route::Share => html! {<ShareTarget/>},
+        Route::UserProfile { username } => html! {<UserProfile {username}/>},
+        Route::Feed => html! {<Feed/>},
     }
 }
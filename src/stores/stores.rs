@@ -4,6 +4,7 @@ use crate::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize};
 use yew::prelude::*;
 use yewdux::prelude::*;
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 #[derive(Clone, Debug, PartialEq)]
@@ -86,19 +87,28 @@ pub struct PaginationItems {
     pub per_page: i32,
 }
 
-#[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Hash)]
+#[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Hash, Default)]
 pub struct Images {
     pub image_url: Option<String>,
     pub small_image_url: Option<String>,
     pub large_image_url: Option<String>,
 }
 
-#[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Hash)]
+#[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Hash, Default)]
 pub struct AnimeImages {
+    #[serde(default)]
     pub jpg: Images,
+    #[serde(default)]
     pub webp: Images,
 }
 
+/// `/anime/{id}/pictures` and `/characters/{id}/pictures`. Character pictures may
+/// come in fewer sizes or formats, which are left `None`.
+#[derive(PartialEq, Serialize, Deserialize, Debug, Clone)]
+pub struct PicturesWrapper {
+    pub data: Vec<AnimeImages>,
+}
+
 #[derive(PartialEq, Serialize, Deserialize, Debug)]
 pub struct Trailers {
     pub youtube_id: Option<String>,
@@ -137,6 +147,8 @@ pub struct AnimeObj {
     pub title_japanese: Option<String>,
     pub source: Option<String>,
     pub episodes: Option<u16>,
+    #[serde(default)]
+    pub duration: Option<String>,
     #[serde(deserialize_with = "deserialize_status_from_option_string")]
     pub status: Option<AnimeStatus>,
     pub airing: bool,
@@ -174,6 +186,8 @@ pub struct AnimeObjFull {
     pub title_japanese: Option<String>,
     pub source: Option<String>,
     pub episodes: Option<u16>,
+    #[serde(default)]
+    pub duration: Option<String>,
     #[serde(deserialize_with = "deserialize_status_from_option_string")]
     pub status: Option<AnimeStatus>,
     pub airing: bool,
@@ -211,6 +225,7 @@ impl From<AnimeObjFull> for AnimeObj {
             title_japanese: v.title_japanese,
             source: v.source,
             episodes: v.episodes,
+            duration: v.duration,
             status: v.status,
             airing: v.airing,
             score: v.score,
@@ -378,6 +393,8 @@ pub type AnimeRecImg = CharImg;
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone)]
 pub struct AnimeRecWrapper {
     pub entry: AnimeRecObj,
+    #[serde(default)]
+    pub votes: u32,
 }
 
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone)]
@@ -402,6 +419,39 @@ pub struct AnimeEpisode {
     pub title_romanji: Option<String>,
     pub aired: Option<String>,
     pub forum_url: Option<String>,
+    /// Average of MAL members' episode votes, from 1 to 5.
+    #[serde(default)]
+    pub score: Option<f32>,
+    #[serde(default)]
+    pub filler: bool,
+    #[serde(default)]
+    pub recap: bool,
+}
+
+/// A single episode from `/anime/{id}/episodes/{episode}`, which adds what the
+/// episode list leaves out.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AnimeEpisodeDetail {
+    pub mal_id: u32,
+    pub url: Option<String>,
+    pub title: String,
+    pub title_japanese: Option<String>,
+    pub title_romanji: Option<String>,
+    /// Seconds.
+    pub duration: Option<u32>,
+    pub aired: Option<String>,
+    #[serde(default)]
+    pub filler: bool,
+    #[serde(default)]
+    pub recap: bool,
+    pub synopsis: Option<String>,
+    #[serde(default)]
+    pub forum_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AnimeEpisodeDetailWrapper {
+    pub data: AnimeEpisodeDetail,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -416,6 +466,19 @@ pub struct AnimeEpisodePagination {
     pub has_next_page: bool,
 }
 
+/// Episode list of the anime on screen, fetched a page at a time as `EpisodeCards`
+/// needs it. Pages stay cached until another anime is opened.
+#[derive(Store, PartialEq, Default, Clone, Debug)]
+pub struct EpisodeStore {
+    pub mal_id: u64,
+    /// Jikan pages by number, each holding up to `EPISODES_PER_PAGE` episodes.
+    pub pages: HashMap<u32, Vec<AnimeEpisode>>,
+    /// From the `pagination` of the pages fetched so far.
+    pub last_page: Option<u32>,
+    pub pending: Vec<u32>,
+    pub failed: Vec<u32>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SeasonObj {
     pub year: u32,
@@ -431,7 +494,8 @@ pub struct SeasonsWrapper {
 #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Hash)]
 pub struct Cache {
     pub anime_details: Option<AnimeObjAsQueryResult>,
-    pub search_result: Option<QueryResult>,
+    /// With the relaxed query the results came from, when the original one found nothing.
+    pub search_result: Option<(QueryResult, Option<String>)>,
     pub anime_result: Option<QueryResult>,
     pub home_page_result: Option<(QueryResult, QueryResult)>,
 }
@@ -455,7 +519,10 @@ impl Cache {
         }
     }
 
-    pub fn update_search_result(&self, search_result: Option<QueryResult>) -> Cache {
+    pub fn update_search_result(
+        &self,
+        search_result: Option<(QueryResult, Option<String>)>,
+    ) -> Cache {
         Cache {
             anime_details: (&self.anime_details).clone(),
             search_result,
@@ -477,6 +544,128 @@ impl Cache {
     }
 }
 
+pub const MAX_COMPARE: usize = 4;
+
+/// Anime picked with "add to compare" on `AnimeCard`, in the order they were added.
+#[derive(Store, PartialEq, Default, Clone, Debug)]
+pub struct CompareList {
+    pub ids: Vec<u64>,
+}
+
+impl CompareList {
+    pub fn contains(&self, mal_id: u64) -> bool {
+        self.ids.contains(&mal_id)
+    }
+
+    /// Adds `mal_id`, or removes it if it is already picked. Adding past
+    /// `MAX_COMPARE` drops the oldest pick.
+    pub fn toggle(&mut self, mal_id: u64) {
+        if let Some(i) = self.ids.iter().position(|id| *id == mal_id) {
+            self.ids.remove(i);
+        } else {
+            if self.ids.len() >= MAX_COMPARE {
+                self.ids.remove(0);
+            }
+            self.ids.push(mal_id);
+        }
+    }
+
+    pub fn to_query(&self) -> CompareQuery {
+        CompareQuery {
+            ids: self
+                .ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        }
+    }
+}
+
+/// Query string of the `/compare` route, e.g. `?ids=5114,9253`.
+#[derive(Serialize, Deserialize, PartialEq, Default, Clone, Debug)]
+pub struct CompareQuery {
+    pub ids: String,
+}
+
+impl CompareQuery {
+    /// Parses the comma separated ids, skipping invalid entries and duplicates.
+    pub fn mal_ids(&self) -> Vec<u64> {
+        let mut ids = Vec::new();
+        for id in self.ids.split(',').filter_map(|s| s.trim().parse::<u64>().ok()) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids.truncate(MAX_COMPARE);
+        ids
+    }
+}
+
+/// Cards already loaded by an infinite-scroll list. Kept outside the component so that
+/// coming back from an anime's page restores the grid and scroll position.
+#[derive(Store, PartialEq, Default, Clone, Debug)]
+pub struct InfiniteScrollState {
+    pub list_key: String,
+    pub items: Vec<AnimeObj>,
+    pub next_page: Option<u32>,
+    pub scroll_y: f64,
+}
+
+/// Which part of an anime's discussion `CommentSection` shows. `EpisodeCards` sets
+/// it when an episode's comments are opened or an episode is marked as watched.
+#[derive(Store, PartialEq, Default, Clone, Debug)]
+pub struct CommentScope {
+    pub mal_id: u32,
+    /// Episode whose comments are shown, `None` for the whole anime.
+    pub episode: Option<u32>,
+    /// Last watched episode, if the user tracks it on this device.
+    pub progress: Option<u32>,
+    /// Shows comments about episodes past `progress` as well.
+    pub show_unwatched: bool,
+}
+
+impl CommentScope {
+    pub fn for_anime(mal_id: u32) -> Self {
+        CommentScope {
+            mal_id,
+            episode: None,
+            progress: load_episode_progress(mal_id),
+            show_unwatched: false,
+        }
+    }
+}
+
+/// Usernames whose comments are collapsed on this device. Loaded from localStorage
+/// when first used; changes are written back with `save_blocked_users`.
+#[derive(Store, PartialEq, Clone, Debug)]
+pub struct BlockList {
+    pub usernames: Vec<String>,
+}
+
+impl Default for BlockList {
+    fn default() -> Self {
+        BlockList {
+            usernames: load_blocked_users(),
+        }
+    }
+}
+
+impl BlockList {
+    pub fn contains(&self, username: &str) -> bool {
+        self.usernames.iter().any(|u| u.eq_ignore_ascii_case(username))
+    }
+
+    /// Blocks `username`, or unblocks it if it is blocked already.
+    pub fn toggle(&mut self, username: &str) {
+        if self.contains(username) {
+            self.usernames.retain(|u| !u.eq_ignore_ascii_case(username));
+        } else {
+            self.usernames.push(username.to_string());
+        }
+    }
+}
+
 #[derive(Store, PartialEq, Default, Clone)]
 pub struct NodeRefStore {
     pub nb_left: NodeRef,
@@ -529,28 +718,350 @@ impl Filter {
     }
 }
 
+/// Credentials for login and registration. The password is sent as typed, over TLS,
+/// and hashed with Argon2 on the server. Accounts registered before that switch are
+/// checked against their old SHA-256 digest server-side and re-hashed on their next
+/// successful login.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct UserRequest {
     pub username: String,
-    pub password_hash: String,
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ChangeUsernameRequest {
+    pub username: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct UserCommentExport {
+    pub anime_id: i32,
+    pub comment: String,
+    pub date: String,
+}
+
+/// Everything the backend keeps about an account, from `/api/v1/users/export`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct UserDataExport {
+    pub username: String,
+    pub uuid: i32,
+    pub fav_anime: Vec<UserAnimeResponse>,
+    pub comments: Vec<UserCommentExport>,
+}
+
+/// The "download my data" file: server-side data plus the settings kept in the app.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DataExportBundle {
+    pub exported_at: String,
+    pub account: UserDataExport,
+    pub settings: UserSettings,
+}
+
+impl UserRequest {
+    pub fn new(username: &str, password: &str) -> Self {
+        UserRequest {
+            username: username.to_string(),
+            password: password.to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UserForResponse {
     pub username: String,
     pub uuid: i32,
-    pub fav_anime: Vec<UserAnimeResponse>
+    pub fav_anime: Vec<UserAnimeResponse>,
+    #[serde(default)]
+    pub role: UserRole,
 }
-#[derive(Debug, Serialize, Deserialize, Clone)]
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    #[default]
+    User,
+    Moderator,
+    Admin,
+}
+
+impl UserRole {
+    /// Can hide and remove other users' comments.
+    pub fn is_moderator(&self) -> bool {
+        matches!(self, UserRole::Moderator | UserRole::Admin)
+    }
+}
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct AnimeCommentGet {
+    #[serde(default)]
+    pub id: i32,
+    /// The comment this one replies to, `None` for top-level comments.
+    #[serde(default)]
+    pub parent_id: Option<i32>,
+    pub username: String,
+    pub comment: String,
+    pub date: String,
+    /// Episode the comment is about, `None` for the anime as a whole.
+    #[serde(default)]
+    pub episode: Option<u32>,
+    #[serde(default)]
+    pub edited_at: Option<String>,
+    /// Deleted comments that still have replies are kept as placeholders.
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub likes: u32,
+    #[serde(default)]
+    pub dislikes: u32,
+    /// The logged-in user's own reaction, only sent for authenticated requests.
+    #[serde(default)]
+    pub my_reaction: Option<Reaction>,
+    /// Hidden by a moderator. Only moderators receive hidden comments.
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// Who can see a user's profile page. Friends are users who follow each other.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfilePrivacy {
+    #[default]
+    Public,
+    FriendsOnly,
+    Private,
+}
+
+impl ProfilePrivacy {
+    pub const ALL: [ProfilePrivacy; 3] = [
+        ProfilePrivacy::Public,
+        ProfilePrivacy::FriendsOnly,
+        ProfilePrivacy::Private,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ProfilePrivacy::Public => "profile.privacy_public",
+            ProfilePrivacy::FriendsOnly => "profile.privacy_friends",
+            ProfilePrivacy::Private => "profile.privacy_private",
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct PublicProfile {
     pub username: String,
+    pub avatar: Option<String>,
+    /// RFC 3339 timestamp of when the account was created.
+    pub joined: String,
+    pub privacy: ProfilePrivacy,
+    pub fav_anime: Vec<UserAnimeResponse>,
+    pub recent_comments: Vec<ProfileComment>,
+    #[serde(default)]
+    pub follows: FollowInfo,
+}
+
+/// What a profile the viewer may not see still shows.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct RestrictedProfile {
+    pub username: String,
+    pub avatar: Option<String>,
+    pub privacy: ProfilePrivacy,
+    #[serde(default)]
+    pub follows: FollowInfo,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct ProfileComment {
+    pub id: i32,
+    pub anime_id: u64,
+    pub anime_title: String,
     pub comment: String,
     pub date: String,
+    #[serde(default)]
+    pub episode: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrivacyPut {
+    pub privacy: ProfilePrivacy,
+}
+
+/// Follower counts of a profile, and whether the viewer follows it.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct FollowInfo {
+    pub followers: u32,
+    pub following: u32,
+    /// Always `false` for logged-out viewers.
+    pub is_following: bool,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedActivityKind {
+    /// Added to favourites.
+    Added,
+    Completed,
+    Rated,
+    Commented,
+}
+
+impl FeedActivityKind {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            FeedActivityKind::Added => "feed.added",
+            FeedActivityKind::Completed => "feed.completed",
+            FeedActivityKind::Rated => "feed.rated",
+            FeedActivityKind::Commented => "feed.commented",
+        }
+    }
+}
+
+/// Something a followed user did, as listed on `/feed`.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct FeedActivity {
+    pub id: i64,
+    pub username: String,
+    pub avatar: Option<String>,
+    pub kind: FeedActivityKind,
+    pub anime_id: u64,
+    pub anime_title: String,
+    pub anime_img: Option<String>,
+    /// Set for `Rated`.
+    #[serde(default)]
+    pub score: Option<u32>,
+    /// Set for `Commented`.
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// RFC 3339 timestamp.
+    pub date: String,
+}
+
+/// Newest activity first.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct FeedPage {
+    pub activities: Vec<FeedActivity>,
+    /// Opaque cursor of the next page, `None` on the last one.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    Spoiler,
+    Offensive,
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 5] = [
+        ReportReason::Spam,
+        ReportReason::Harassment,
+        ReportReason::Spoiler,
+        ReportReason::Offensive,
+        ReportReason::Other,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "report.spam",
+            ReportReason::Harassment => "report.harassment",
+            ReportReason::Spoiler => "report.spoiler",
+            ReportReason::Offensive => "report.offensive",
+            ReportReason::Other => "report.other",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommentReport {
+    pub reason: ReportReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommentVisibility {
+    pub hidden: bool,
+}
+
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Reaction {
+    Like,
+    Dislike,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReactionPut {
+    pub reaction: Reaction,
+}
+
+/// Order of top-level comments. Replies always follow their parent oldest first.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub enum CommentSort {
+    #[default]
+    Newest,
+    Oldest,
+    /// Most likes minus dislikes first.
+    Top,
+}
+
+impl CommentSort {
+    pub const ALL: [CommentSort; 3] = [CommentSort::Newest, CommentSort::Oldest, CommentSort::Top];
+
+    pub fn as_query(&self) -> &'static str {
+        match self {
+            CommentSort::Newest => "newest",
+            CommentSort::Oldest => "oldest",
+            CommentSort::Top => "top",
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            CommentSort::Newest => "comments.sort_newest",
+            CommentSort::Oldest => "comments.sort_oldest",
+            CommentSort::Top => "comments.sort_top",
+        }
+    }
+}
+
+/// One page of an anime's comments: top-level comments in the requested order, each
+/// followed by all of its replies.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+pub struct CommentPage {
+    pub comments: Vec<AnimeCommentGet>,
+    /// Opaque cursor of the next page, `None` on the last one.
+    pub next_cursor: Option<String>,
+    /// Number of comments in the whole discussion, replies included.
+    #[serde(default)]
+    pub total: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AnimeCommentPost {
     pub comment: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AnimeCommentEdit {
+    pub comment: String,
 }
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
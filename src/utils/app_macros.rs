@@ -17,4 +17,17 @@ macro_rules! get_elem_by_id {
     };
 }
 
+/// Looks up a UI message in the current locale's catalog, see `utils::i18n`.
+/// `t!("comments.count", count = n)` picks the plural form matching `n`;
+/// other named arguments fill `{name}` placeholders.
+macro_rules! t {
+    ($key:expr) => {
+        $crate::prelude::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::prelude::translate($key, &[$((stringify!($name), ($value).to_string())),+])
+    };
+}
+
 pub(crate) use get_elem_by_id;
+pub(crate) use t;
@@ -0,0 +1,182 @@
+use crate::prelude::*;
+
+const CHART_WIDTH: f64 = 560.0;
+const ROW_HEIGHT: f64 = 22.0;
+const ROW_GAP: f64 = 6.0;
+const LABEL_WIDTH: f64 = 150.0;
+const COLUMN_CHART_HEIGHT: f64 = 180.0;
+const COLUMN_LABEL_SPACE: f64 = 60.0;
+
+fn truncate_label(s: &str, max: usize) -> String {
+    if s.chars().count() > max {
+        format!("{}…", s.chars().take(max - 1).collect::<String>())
+    } else {
+        s.to_string()
+    }
+}
+
+fn max_count(items: &[(String, u32)]) -> f64 {
+    items.iter().map(|(_, n)| *n).max().unwrap_or(0).max(1) as f64
+}
+
+/// One labelled horizontal bar per item, e.g. genre or studio distributions.
+pub fn horizontal_bar_chart(items: &[(String, u32)]) -> Html {
+    let max = max_count(items);
+    let bar_space = CHART_WIDTH - LABEL_WIDTH - 40.0;
+    let height = (items.len() as f64 * (ROW_HEIGHT + ROW_GAP)).max(ROW_HEIGHT);
+
+    html! {
+        <svg class="chart" viewBox={format!("0 0 {} {}", CHART_WIDTH, height)} width="100%">
+        {
+            items.iter().enumerate().map(|(i, (label, n))| {
+                let y = i as f64 * (ROW_HEIGHT + ROW_GAP);
+                let w = (*n as f64 / max) * bar_space;
+                let text_y = y + ROW_HEIGHT * 0.72;
+                html! {
+                    <g>
+                        <title>{format!("{}: {}", label, n)}</title>
+                        <text class="chart-label chart-label-end" x={(LABEL_WIDTH - 8.0).to_string()} y={text_y.to_string()}>{truncate_label(label, 20)}</text>
+                        <rect class="chart-bar" x={LABEL_WIDTH.to_string()} y={y.to_string()} width={w.to_string()} height={ROW_HEIGHT.to_string()} rx="4"/>
+                        <text class="chart-value" x={(LABEL_WIDTH + w + 6.0).to_string()} y={text_y.to_string()}>{n}</text>
+                    </g>
+                }
+            }).collect::<Html>()
+        }
+        </svg>
+    }
+}
+
+/// Vertical columns with labels underneath, e.g. score distribution or a timeline.
+/// Labels are slanted when there are too many columns to fit them side by side.
+pub fn column_chart(items: &[(String, u32)]) -> Html {
+    let max = max_count(items);
+    let n = items.len().max(1) as f64;
+    let slot = CHART_WIDTH / n;
+    let col_width = (slot * 0.7).max(2.0);
+    let slanted = slot < 48.0;
+    let total_height = COLUMN_CHART_HEIGHT + COLUMN_LABEL_SPACE;
+
+    html! {
+        <svg class="chart" viewBox={format!("0 0 {} {}", CHART_WIDTH, total_height)} width="100%">
+            <line class="chart-axis" x1="0" y1={COLUMN_CHART_HEIGHT.to_string()} x2={CHART_WIDTH.to_string()} y2={COLUMN_CHART_HEIGHT.to_string()}/>
+        {
+            items.iter().enumerate().map(|(i, (label, count))| {
+                // Leave room above the tallest column for its value
+                let h = (*count as f64 / max) * (COLUMN_CHART_HEIGHT - 20.0);
+                let x = i as f64 * slot + (slot - col_width) / 2.0;
+                let center = i as f64 * slot + slot / 2.0;
+                let label_y = COLUMN_CHART_HEIGHT + 16.0;
+                html! {
+                    <g>
+                        <title>{format!("{}: {}", label, count)}</title>
+                        <rect class="chart-bar" x={x.to_string()} y={(COLUMN_CHART_HEIGHT - h).to_string()} width={col_width.to_string()} height={h.to_string()} rx="3"/>
+                        {
+                            if *count > 0 && !slanted {
+                                html!{<text class="chart-value chart-label-middle" x={center.to_string()} y={(COLUMN_CHART_HEIGHT - h - 4.0).to_string()}>{count}</text>}
+                            } else {
+                                html!{}
+                            }
+                        }
+                        {
+                            if slanted {
+                                html!{
+                                    <text class="chart-label chart-label-end" x={center.to_string()} y={label_y.to_string()} transform={format!("rotate(-45 {} {})", center, label_y)}>{truncate_label(label, 12)}</text>
+                                }
+                            } else {
+                                html!{
+                                    <text class="chart-label chart-label-middle" x={center.to_string()} y={label_y.to_string()}>{truncate_label(label, 12)}</text>
+                                }
+                            }
+                        }
+                    </g>
+                }
+            }).collect::<Html>()
+        }
+        </svg>
+    }
+}
+
+const SPARKLINE_HEIGHT: f64 = 48.0;
+/// Jikan scores episodes from 1 to 5.
+const EPISODE_SCORE_MIN: f64 = 1.0;
+const EPISODE_SCORE_MAX: f64 = 5.0;
+
+/// Runs of consecutive episodes that have a score, so the line breaks where one is
+/// missing instead of dropping to zero.
+fn scored_runs(scores: &[(u32, Option<f32>)]) -> Vec<Vec<(usize, f32)>> {
+    let mut runs: Vec<Vec<(usize, f32)>> = vec![];
+    let mut current = vec![];
+    for (i, (_, score)) in scores.iter().enumerate() {
+        match score {
+            Some(s) => current.push((i, *s)),
+            None if !current.is_empty() => runs.push(std::mem::take(&mut current)),
+            None => {}
+        }
+    }
+    if !current.is_empty() {
+        runs.push(current);
+    }
+    runs
+}
+
+/// Score of each episode in `scores` (episode number, score) as a small line chart.
+pub fn episode_sparkline(scores: &[(u32, Option<f32>)]) -> Html {
+    let slot = CHART_WIDTH / scores.len().max(1) as f64;
+    let x = |i: usize| i as f64 * slot + slot / 2.0;
+    let y = |s: f32| {
+        let t = ((s as f64 - EPISODE_SCORE_MIN) / (EPISODE_SCORE_MAX - EPISODE_SCORE_MIN)).clamp(0.0, 1.0);
+        4.0 + (1.0 - t) * (SPARKLINE_HEIGHT - 8.0)
+    };
+
+    html! {
+        <svg class="chart sparkline" viewBox={format!("0 0 {} {}", CHART_WIDTH, SPARKLINE_HEIGHT)} width="100%">
+        {
+            scored_runs(scores).iter().map(|run| {
+                let points = run
+                    .iter()
+                    .map(|(i, s)| format!("{:.1},{:.1}", x(*i), y(*s)))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                html! {<polyline class="sparkline-line" points={points}/>}
+            }).collect::<Html>()
+        }
+        {
+            scores.iter().enumerate().filter_map(|(i, (n, score))| score.map(|s| html! {
+                <circle class="sparkline-point" cx={x(i).to_string()} cy={y(s).to_string()} r="2.5">
+                    <title>{t!("episodes.score_tooltip", n = n, score = format_number(s as f64, 2))}</title>
+                </circle>
+            })).collect::<Html>()
+        }
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_break_the_line() {
+        let scores = [
+            (1, Some(4.2)),
+            (2, Some(4.5)),
+            (3, None),
+            (4, Some(3.9)),
+            (5, None),
+            (6, None),
+            (7, Some(4.8)),
+        ];
+        assert_eq!(
+            scored_runs(&scores),
+            vec![
+                vec![(0, 4.2), (1, 4.5)],
+                vec![(3, 3.9)],
+                vec![(6, 4.8)],
+            ]
+        );
+        // Unscored episodes are left out rather than plotted at zero
+        assert!(scored_runs(&scores).iter().flatten().all(|(_, s)| *s > 0.0));
+        assert!(scored_runs(&[(1, None), (2, None)]).is_empty());
+        assert!(scored_runs(&[]).is_empty());
+    }
+}
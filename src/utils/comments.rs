@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// Replies are indented up to this depth; deeper ones line up with their parent.
+pub const MAX_REPLY_DEPTH: usize = 4;
+/// Top-level comments per page; their replies come along regardless.
+pub const COMMENT_PAGE_SIZE: u32 = 20;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommentThread {
+    pub comment: AnimeCommentGet,
+    pub replies: Vec<CommentThread>,
+}
+
+/// Nests the flat list the backend returns into reply threads, keeping the order of
+/// `comments` among siblings. Replies whose parent is missing are shown at the top
+/// level rather than dropped.
+pub fn build_threads(comments: &[AnimeCommentGet]) -> Vec<CommentThread> {
+    let ids = comments.iter().map(|c| c.id).collect::<Vec<i32>>();
+    let mut children: HashMap<Option<i32>, Vec<&AnimeCommentGet>> = HashMap::new();
+    for c in comments {
+        let parent = c.parent_id.filter(|p| *p != c.id && ids.contains(p));
+        children.entry(parent).or_default().push(c);
+    }
+
+    fn collect(
+        parent: Option<i32>,
+        children: &HashMap<Option<i32>, Vec<&AnimeCommentGet>>,
+        seen: &mut Vec<i32>,
+    ) -> Vec<CommentThread> {
+        children
+            .get(&parent)
+            .map(|cs| {
+                cs.iter()
+                    .filter_map(|c| {
+                        // A cycle in bad data must not recurse forever
+                        if seen.contains(&c.id) {
+                            return None;
+                        }
+                        seen.push(c.id);
+                        Some(CommentThread {
+                            comment: (*c).clone(),
+                            replies: collect(Some(c.id), children, seen),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    collect(None, &children, &mut vec![])
+}
+
+/// Whether a thread is about an episode past `progress`, the last one watched.
+/// Without known progress nothing counts as unwatched.
+pub fn is_unwatched(thread: &CommentThread, progress: Option<u32>) -> bool {
+    match (thread.comment.episode, progress) {
+        (Some(episode), Some(p)) => episode > p,
+        _ => false,
+    }
+}
+
+/// The threads `scope` shows, and how many were held back as being about episodes
+/// the user has not watched yet. Replies follow their top-level comment.
+pub fn scope_threads(threads: Vec<CommentThread>, scope: &CommentScope) -> (Vec<CommentThread>, usize) {
+    let in_scope = threads
+        .into_iter()
+        .filter(|t| scope.episode.is_none() || t.comment.episode == scope.episode)
+        .collect::<Vec<CommentThread>>();
+    if scope.show_unwatched {
+        return (in_scope, 0);
+    }
+    let (hidden, shown): (Vec<CommentThread>, Vec<CommentThread>) = in_scope
+        .into_iter()
+        .partition(|t| is_unwatched(t, scope.progress));
+    (shown, hidden.len())
+}
+
+/// Reaction after clicking `clicked`: clicking the current reaction again takes it back.
+pub fn next_reaction(current: Option<Reaction>, clicked: Reaction) -> Option<Reaction> {
+    if current == Some(clicked) {
+        None
+    } else {
+        Some(clicked)
+    }
+}
+
+/// `comment` with the user's reaction changed to `reaction` and the counts adjusted,
+/// so the change shows before the backend confirms it.
+pub fn apply_reaction(comment: &AnimeCommentGet, reaction: Option<Reaction>) -> AnimeCommentGet {
+    let mut c = comment.clone();
+    match c.my_reaction {
+        Some(Reaction::Like) => c.likes = c.likes.saturating_sub(1),
+        Some(Reaction::Dislike) => c.dislikes = c.dislikes.saturating_sub(1),
+        None => {}
+    }
+    match reaction {
+        Some(Reaction::Like) => c.likes += 1,
+        Some(Reaction::Dislike) => c.dislikes += 1,
+        None => {}
+    }
+    c.my_reaction = reaction;
+    c
+}
+
+/// `loaded` followed by the comments of the next page it does not have yet. Pages can
+/// overlap when comments are posted while browsing.
+pub fn append_comment_page(
+    loaded: &[AnimeCommentGet],
+    page: Vec<AnimeCommentGet>,
+) -> Vec<AnimeCommentGet> {
+    let fresh = page
+        .into_iter()
+        .filter(|c| !loaded.iter().any(|l| l.id == c.id))
+        .collect::<Vec<AnimeCommentGet>>();
+    [loaded.to_vec(), fresh].concat()
+}
+
+/// Fetches a page of the anime's comments. Logged-in users fetch through `AuthClient`
+/// so that `my_reaction` is filled in.
+pub async fn fetch_comment_page(
+    cx: &AppContext,
+    anime_id: i32,
+    sort: CommentSort,
+    episode: Option<u32>,
+    cursor: Option<String>,
+) -> Result<CommentPage, gloo::net::Error> {
+    let mut url = format!(
+        "{}/api/v1/anime/{}/comment?sort={}&limit={}",
+        BASE_URL,
+        anime_id,
+        sort.as_query(),
+        COMMENT_PAGE_SIZE
+    );
+    if let Some(n) = episode {
+        url.push_str(&format!("&episode={}", n));
+    }
+    if let Some(cursor) = cursor {
+        url.push_str(&format!(
+            "&cursor={}",
+            String::from(web_sys::js_sys::encode_uri_component(&cursor))
+        ));
+    }
+
+    if (*cx).jwt.is_some() {
+        AuthClient::new(cx).get_json::<CommentPage>(&url).await
+    } else {
+        force_req::<CommentPage>(&url).await
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommentPostError {
+    /// Still within the cooldown after the previous post, seconds left.
+    Cooldown(u32),
+    /// The backend answered 429, seconds to wait.
+    RateLimited(u32),
+    Failed,
+}
+
+impl CommentPostError {
+    pub fn message(&self) -> String {
+        match self {
+            CommentPostError::Cooldown(secs) | CommentPostError::RateLimited(secs) => {
+                t!("comments.cooldown", count = secs)
+            }
+            CommentPostError::Failed => t!("comments.post_failed"),
+        }
+    }
+}
+
+/// Posts a comment or reply, honouring the posting cooldown. A 429 extends the
+/// cooldown by however long the backend asks for.
+pub async fn post_comment(
+    cx: &AppContext,
+    anime_id: i32,
+    post: &AnimeCommentPost,
+) -> Result<(), CommentPostError> {
+    if let Some(secs) = post_cooldown_remaining() {
+        return Err(CommentPostError::Cooldown(secs));
+    }
+    let response = AuthClient::new(cx)
+        .post_json(
+            &format!("{}/api/v1/anime/{}/comment", BASE_URL, anime_id),
+            post,
+        )
+        .await
+        .map_err(|e| {
+            log!(format!("{:?}", e));
+            CommentPostError::Failed
+        })?;
+    match response.status() {
+        200 | 201 => {
+            start_post_cooldown(POST_COOLDOWN_MS);
+            Ok(())
+        }
+        429 => {
+            let secs = retry_after_secs(response.headers().get("Retry-After"));
+            start_post_cooldown(secs as f64 * 1000.0);
+            Err(CommentPostError::RateLimited(secs))
+        }
+        _ => Err(CommentPostError::Failed),
+    }
+}
+
+/// Sets or, with `None`, removes the user's reaction to a comment.
+pub async fn send_reaction(
+    cx: &AppContext,
+    comment_id: i32,
+    reaction: Option<Reaction>,
+) -> Result<(), gloo::net::Error> {
+    let client = AuthClient::new(cx);
+    let url = format!("{}/api/v1/comment/{}/reaction", BASE_URL, comment_id);
+    let response = match reaction {
+        Some(reaction) => client.put_json(&url, &ReactionPut { reaction }).await?,
+        None => client.delete(&url).await?,
+    };
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.react_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// Short form of a comment count for the badge on the section icon, e.g. `1.2k`.
+pub fn format_count_badge(n: u32) -> String {
+    match n {
+        0..=999 => n.to_string(),
+        1_000..=9_999 => format!("{:.1}k", (n / 100) as f64 / 10.0).replace(".0k", "k"),
+        _ => format!("{}k", n / 1_000),
+    }
+}
+
+pub fn is_comment_author(cx: &AppContext, comment: &AnimeCommentGet) -> bool {
+    !comment.deleted && (*cx).username.as_deref() == Some(comment.username.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: i32, parent_id: Option<i32>) -> AnimeCommentGet {
+        AnimeCommentGet {
+            id,
+            parent_id,
+            username: "user".to_string(),
+            comment: id.to_string(),
+            date: String::new(),
+            episode: None,
+            edited_at: None,
+            deleted: false,
+            likes: 0,
+            dislikes: 0,
+            my_reaction: None,
+            hidden: false,
+        }
+    }
+
+    fn shape(threads: &[CommentThread]) -> Vec<(i32, Vec<i32>)> {
+        threads
+            .iter()
+            .map(|t| (t.comment.id, t.replies.iter().map(|r| r.comment.id).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn nests_replies_in_order() {
+        let comments = vec![
+            comment(1, None),
+            comment(2, Some(1)),
+            comment(3, None),
+            comment(4, Some(1)),
+            comment(5, Some(2)),
+        ];
+        let threads = build_threads(&comments);
+        assert_eq!(shape(&threads), vec![(1, vec![2, 4]), (3, vec![])]);
+        assert_eq!(threads[0].replies[0].replies[0].comment.id, 5);
+    }
+
+    #[test]
+    fn orphans_and_cycles() {
+        let comments = vec![comment(1, Some(99)), comment(2, Some(2))];
+        assert_eq!(shape(&build_threads(&comments)), vec![(1, vec![]), (2, vec![])]);
+
+        // 3 and 4 reply to each other, so neither is reachable from the top level
+        let cycle = vec![comment(3, Some(4)), comment(4, Some(3))];
+        assert!(build_threads(&cycle).is_empty());
+    }
+
+    #[test]
+    fn scoping_by_episode_and_progress() {
+        let comments = [None, Some(1), Some(2), Some(5)]
+            .iter()
+            .enumerate()
+            .map(|(i, episode)| AnimeCommentGet {
+                episode: *episode,
+                ..comment(i as i32, None)
+            })
+            .collect::<Vec<AnimeCommentGet>>();
+        let ids = |(threads, hidden): (Vec<CommentThread>, usize)| {
+            (threads.iter().map(|t| t.comment.id).collect::<Vec<i32>>(), hidden)
+        };
+
+        let mut scope = CommentScope::default();
+        assert_eq!(ids(scope_threads(build_threads(&comments), &scope)), (vec![0, 1, 2, 3], 0));
+
+        scope.progress = Some(1);
+        assert_eq!(ids(scope_threads(build_threads(&comments), &scope)), (vec![0, 1], 2));
+
+        scope.episode = Some(5);
+        assert_eq!(ids(scope_threads(build_threads(&comments), &scope)), (vec![], 1));
+
+        scope.show_unwatched = true;
+        assert_eq!(ids(scope_threads(build_threads(&comments), &scope)), (vec![3], 0));
+    }
+
+    #[test]
+    fn reactions() {
+        let c = AnimeCommentGet {
+            likes: 3,
+            dislikes: 1,
+            ..comment(1, None)
+        };
+        let liked = apply_reaction(&c, next_reaction(c.my_reaction, Reaction::Like));
+        assert_eq!((liked.likes, liked.dislikes, liked.my_reaction), (4, 1, Some(Reaction::Like)));
+
+        let disliked = apply_reaction(&liked, next_reaction(liked.my_reaction, Reaction::Dislike));
+        assert_eq!((disliked.likes, disliked.dislikes), (3, 2));
+
+        let undone = apply_reaction(&disliked, next_reaction(disliked.my_reaction, Reaction::Dislike));
+        assert_eq!(undone, c);
+    }
+
+    #[test]
+    fn count_badge() {
+        assert_eq!(format_count_badge(999), "999");
+        assert_eq!(format_count_badge(1_000), "1k");
+        assert_eq!(format_count_badge(1_290), "1.2k");
+        assert_eq!(format_count_badge(25_400), "25k");
+    }
+
+    #[test]
+    fn appending_pages_skips_overlap() {
+        let loaded = vec![comment(1, None), comment(2, None)];
+        let page = vec![comment(2, None), comment(3, None), comment(4, Some(3))];
+        let ids = append_comment_page(&loaded, page)
+            .iter()
+            .map(|c| c.id)
+            .collect::<Vec<i32>>();
+        assert_eq!(ids, vec![1, 2, 3, 4]);
+    }
+}
@@ -75,12 +75,10 @@ pub fn handle_long_name(s: &str) -> Html {
 }
 
 pub fn handle_theme(app_ctx: &AppContext) -> String {
-    if let Theme::Light = (*app_ctx).theme {
+    if let Theme::Light = (*app_ctx).theme.resolve((*app_ctx).prefers_dark) {
         "light".to_string()
-    } else if let Theme::Dark = (*app_ctx).theme {
-        "dark".to_string()
     } else {
-        "".to_owned()
+        "dark".to_string()
     }
 }
 
@@ -92,26 +90,6 @@ pub fn handle_nsfw(app_ctx: &AppContext) -> &'static str {
     }
 }
 
-pub fn extract_title_from_cx(ao: &AnimeObj, cx: &AppContext) -> String {
-    let pref_lang = &(cx.language);
-    match pref_lang {
-        &Language::EN => {
-            if let Some(t) = &((*ao).title_english) {
-                return t.to_string();
-            } else {
-                return ((*ao).titles[0].title).to_string();
-            }
-        }
-        &Language::JP => {
-            if let Some(t) = &((*ao).title_english) {
-                return t.to_string();
-            } else {
-                return ((*ao).titles[0].title).to_string();
-            }
-        }
-    }
-}
-
 pub fn season_from_month(m: &str) -> &'static str {
     match m {
         "01" | "02" | "03" => "Winter",
@@ -122,6 +100,16 @@ pub fn season_from_month(m: &str) -> &'static str {
     }
 }
 
+/// Title of the current season's list, e.g. "Winter 2024's Selection".
+pub fn seasonal_title(now: &DateTime<Utc>) -> String {
+    let season = season_from_month(&format!("{}", now.format("%m")));
+    t!(
+        "home.seasonal",
+        season = t!(&format!("season.{}", season.to_lowercase())),
+        year = now.format("%Y")
+    )
+}
+
 pub fn extract_img_from_cx(ao: &AnimeObj) -> String {
     ao.images
         .webp
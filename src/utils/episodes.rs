@@ -0,0 +1,257 @@
+use gloo::net::http::Request;
+
+use crate::prelude::*;
+
+/// Episodes per page of Jikan's episode list, which is also the size of the ranges
+/// `EpisodeCards` shows.
+pub const EPISODES_PER_PAGE: usize = 100;
+
+/// Page of the episode list that holds episode `n`, counting from 1.
+pub fn episode_page(n: usize) -> u32 {
+    (n.saturating_sub(1) / EPISODES_PER_PAGE + 1) as u32
+}
+
+impl EpisodeStore {
+    pub fn for_anime(mal_id: u64) -> Self {
+        EpisodeStore {
+            mal_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn insert_page(&mut self, page: u32, wrapper: AnimeEpisodeWrapper) {
+        self.last_page = Some(wrapper.pagination.last_visible_page.max(page));
+        self.pages.insert(page, wrapper.data);
+    }
+
+    /// Episode `n`, if its page has been fetched and lists it.
+    pub fn episode(&self, n: usize) -> Option<&AnimeEpisode> {
+        self.pages
+            .get(&episode_page(n))
+            .and_then(|eps| eps.get((n - 1) % EPISODES_PER_PAGE))
+    }
+
+    pub fn has_page(&self, page: u32) -> bool {
+        self.pages.contains_key(&page)
+    }
+
+    /// Number of episodes. `listed` is the count from the anime's details, which is
+    /// used when known; otherwise it is exact once the last page has been fetched.
+    pub fn total(&self, listed: Option<usize>) -> Option<usize> {
+        listed.filter(|n| *n > 0).or_else(|| {
+            let last = self.last_page?;
+            self.pages
+                .get(&last)
+                .map(|eps| (last as usize - 1) * EPISODES_PER_PAGE + eps.len())
+        })
+    }
+
+    /// Number of ranges to offer, before every page is known.
+    pub fn page_count(&self, listed: Option<usize>) -> u32 {
+        match listed.filter(|n| *n > 0) {
+            Some(n) => episode_page(n),
+            None => self.last_page.unwrap_or(1),
+        }
+    }
+
+    /// First and last episode of range `page`. `None` for the last range while its
+    /// page, and so its length, is still unknown.
+    pub fn page_range(&self, page: u32, listed: Option<usize>) -> Option<(usize, usize)> {
+        let start = (page as usize - 1) * EPISODES_PER_PAGE + 1;
+        let full = page as usize * EPISODES_PER_PAGE;
+        match self.total(listed) {
+            Some(total) => Some((start, full.min(total))),
+            None if page < self.page_count(listed) => Some((start, full)),
+            None => None,
+        }
+    }
+}
+
+/// Fetches a page of `mal_id`'s episode list into the store unless it is cached or
+/// already on its way. Opening another anime drops the pages of the previous one.
+pub fn load_episode_page(dispatch: Dispatch<EpisodeStore>, mal_id: u64, page: u32) {
+    let store = dispatch.get();
+    if store.mal_id == mal_id && (store.has_page(page) || store.pending.contains(&page)) {
+        return;
+    }
+    dispatch.reduce_mut(|s| {
+        if s.mal_id != mal_id {
+            *s = EpisodeStore::for_anime(mal_id);
+        }
+        s.pending.push(page);
+        s.failed.retain(|p| *p != page);
+    });
+
+    wasm_bindgen_futures::spawn_local(async move {
+        let result = force_req::<AnimeEpisodeWrapper>(&format!(
+            "https://api.jikan.moe/v4/anime/{}/episodes?page={}",
+            mal_id, page
+        ))
+        .await;
+        dispatch.reduce_mut(|s| {
+            // Another anime was opened in the meantime
+            if s.mal_id != mal_id {
+                return;
+            }
+            s.pending.retain(|p| *p != page);
+            match result {
+                Ok(wrapper) => s.insert_page(page, wrapper),
+                Err(e) => {
+                    log!(format!("{:?}", e));
+                    s.failed.push(page);
+                }
+            }
+        });
+    });
+}
+
+impl From<&AnimeEpisodeDetail> for AnimeEpisode {
+    fn from(detail: &AnimeEpisodeDetail) -> Self {
+        AnimeEpisode {
+            mal_id: detail.mal_id,
+            url: detail.url.clone(),
+            title: detail.title.clone(),
+            title_japanese: detail.title_japanese.clone(),
+            title_romanji: detail.title_romanji.clone(),
+            aired: detail.aired.clone(),
+            forum_url: detail.forum_url.clone(),
+            score: None,
+            filler: detail.filler,
+            recap: detail.recap,
+        }
+    }
+}
+
+/// Fetches one episode, `None` if the anime has no such episode.
+pub async fn fetch_episode_detail(mal_id: u64, episode: u32) -> Result<Option<AnimeEpisodeDetail>, gloo::net::Error> {
+    let url = format!("https://api.jikan.moe/v4/anime/{}/episodes/{}", mal_id, episode);
+    let response = Request::get(&url).send().await?;
+    match response.status() {
+        // Not retried like a rate limit would be
+        404 => Ok(None),
+        s if (200..300).contains(&s) => Ok(Some(response.json::<AnimeEpisodeDetailWrapper>().await?.data)),
+        _ => force_req::<AnimeEpisodeDetailWrapper>(&url)
+            .await
+            .map(|wrapper| Some(wrapper.data)),
+    }
+}
+
+/// When an episode aired, as wall-clock time at `offset`.
+pub fn aired_at_offset(aired: &str, offset: &FixedOffset) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(aired)
+        .ok()
+        .map(|dt| dt.with_timezone(offset).naive_local())
+}
+
+/// When an episode aired, in the user's time zone.
+pub fn aired_local(aired: &str) -> Option<NaiveDateTime> {
+    aired_at_offset(aired, Local::now().offset())
+}
+
+/// Previous and next episode of `n`. With an unknown total, e.g. while airing, the
+/// next one is offered anyway.
+pub fn adjacent_episodes(n: u32, total: Option<u32>) -> (Option<u32>, Option<u32>) {
+    let prev = (n > 1).then(|| n - 1);
+    let next = match total {
+        Some(total) if n >= total => None,
+        _ => Some(n + 1),
+    };
+    (prev, next)
+}
+
+/// Length in whole minutes, rounded to the nearest one.
+pub fn duration_minutes(seconds: u32) -> u32 {
+    (seconds + 30) / 60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aired_in_other_zones() {
+        let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+        let new_york = FixedOffset::west_opt(5 * 3600).unwrap();
+        let aired = "2002-10-03T00:00:00+00:00";
+        assert_eq!(
+            aired_at_offset(aired, &tokyo),
+            NaiveDate::from_ymd_opt(2002, 10, 3).and_then(|d| d.and_hms_opt(9, 0, 0))
+        );
+        assert_eq!(
+            aired_at_offset(aired, &new_york),
+            NaiveDate::from_ymd_opt(2002, 10, 2).and_then(|d| d.and_hms_opt(19, 0, 0))
+        );
+        assert_eq!(aired_at_offset("2002-10-03", &tokyo), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        assert_eq!(adjacent_episodes(1, Some(12)), (None, Some(2)));
+        assert_eq!(adjacent_episodes(12, Some(12)), (Some(11), None));
+        assert_eq!(adjacent_episodes(30, None), (Some(29), Some(31)));
+    }
+
+    fn page(n: usize, last_visible_page: u32) -> AnimeEpisodeWrapper {
+        AnimeEpisodeWrapper {
+            data: (0..n)
+                .map(|i| AnimeEpisode {
+                    mal_id: i as u32 + 1,
+                    url: None,
+                    title: format!("{}", i + 1),
+                    title_japanese: None,
+                    title_romanji: None,
+                    aired: None,
+                    forum_url: None,
+                    score: None,
+                    filler: false,
+                    recap: false,
+                })
+                .collect(),
+            pagination: AnimeEpisodePagination {
+                last_visible_page,
+                has_next_page: last_visible_page > 1,
+            },
+        }
+    }
+
+    #[test]
+    fn episode_pages() {
+        assert_eq!(episode_page(1), 1);
+        assert_eq!(episode_page(100), 1);
+        assert_eq!(episode_page(101), 2);
+
+        let mut store = EpisodeStore::for_anime(21);
+        assert_eq!(store.page_range(1, None), None);
+
+        store.insert_page(1, page(100, 3));
+        assert_eq!(store.episode(100).map(|e| e.mal_id), Some(100));
+        assert_eq!(store.episode(101), None);
+        assert_eq!(store.total(None), None);
+        assert_eq!(store.page_count(None), 3);
+        assert_eq!(store.page_range(2, None), Some((101, 200)));
+        assert_eq!(store.page_range(3, None), None);
+
+        store.insert_page(3, page(37, 3));
+        assert_eq!(store.total(None), Some(237));
+        assert_eq!(store.page_range(3, None), Some((201, 237)));
+        assert_eq!(store.episode(237).map(|e| e.mal_id), Some(37));
+    }
+
+    #[test]
+    fn listed_count_wins() {
+        let mut store = EpisodeStore::for_anime(1);
+        assert_eq!(store.page_range(1, Some(12)), Some((1, 12)));
+        assert_eq!(store.page_count(Some(220)), 3);
+
+        // No episode list on Jikan at all
+        store.insert_page(1, page(0, 1));
+        assert_eq!(store.total(None), Some(0));
+    }
+
+    #[test]
+    fn minutes() {
+        assert_eq!(duration_minutes(1440), 24);
+        assert_eq!(duration_minutes(1415), 24);
+        assert_eq!(duration_minutes(1409), 23);
+    }
+}
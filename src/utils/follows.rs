@@ -0,0 +1,133 @@
+use crate::prelude::*;
+
+pub const FEED_PAGE_SIZE: u32 = 30;
+
+/// Follows `username`, or unfollows with `follow == false`.
+pub async fn set_following(cx: &AppContext, username: &str, follow: bool) -> Result<(), gloo::net::Error> {
+    let client = AuthClient::new(cx);
+    let url = format!(
+        "{}/api/v1/users/{}/follow",
+        BASE_URL,
+        String::from(web_sys::js_sys::encode_uri_component(username))
+    );
+    let response = if follow {
+        client.post(&url).await?
+    } else {
+        client.delete(&url).await?
+    };
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.follow_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// `info` after the viewer follows or unfollows, so the button and counts change
+/// before the backend confirms it.
+pub fn apply_follow(info: FollowInfo, follow: bool) -> FollowInfo {
+    let followers = match (info.is_following, follow) {
+        (false, true) => info.followers + 1,
+        (true, false) => info.followers.saturating_sub(1),
+        _ => info.followers,
+    };
+    FollowInfo {
+        followers,
+        is_following: follow,
+        ..info
+    }
+}
+
+/// Activity of the users the viewer follows, newest first.
+pub async fn fetch_feed(cx: &AppContext, cursor: Option<String>) -> Result<FeedPage, gloo::net::Error> {
+    let mut url = format!("{}/api/v1/feed?limit={}", BASE_URL, FEED_PAGE_SIZE);
+    if let Some(cursor) = cursor {
+        url.push_str(&format!(
+            "&cursor={}",
+            String::from(web_sys::js_sys::encode_uri_component(&cursor))
+        ));
+    }
+    AuthClient::new(cx).get_json::<FeedPage>(&url).await
+}
+
+/// `loaded` followed by the activities of the next page it does not have yet.
+pub fn append_feed_page(loaded: &[FeedActivity], page: Vec<FeedActivity>) -> Vec<FeedActivity> {
+    let fresh = page
+        .into_iter()
+        .filter(|a| !loaded.iter().any(|l| l.id == a.id))
+        .collect::<Vec<FeedActivity>>();
+    [loaded.to_vec(), fresh].concat()
+}
+
+/// Splits the feed into runs of activities from the same day, keeping their order.
+/// Activities with an unreadable date join the run before them.
+pub fn group_feed_by_day(activities: &[FeedActivity]) -> Vec<(Option<NaiveDate>, Vec<FeedActivity>)> {
+    let mut days: Vec<(Option<NaiveDate>, Vec<FeedActivity>)> = vec![];
+    for a in activities {
+        let day = joined_date(&a.date);
+        match days.last_mut() {
+            Some((d, items)) if day.is_none() || *d == day => items.push(a.clone()),
+            _ => days.push((day, vec![a.clone()])),
+        }
+    }
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(id: i64, date: &str) -> FeedActivity {
+        FeedActivity {
+            id,
+            username: "user".to_string(),
+            avatar: None,
+            kind: FeedActivityKind::Added,
+            anime_id: 1,
+            anime_title: String::new(),
+            anime_img: None,
+            score: None,
+            comment: None,
+            date: date.to_string(),
+        }
+    }
+
+    #[test]
+    fn follow_counts() {
+        let info = FollowInfo {
+            followers: 2,
+            following: 5,
+            is_following: false,
+        };
+        let followed = apply_follow(info, true);
+        assert_eq!((followed.followers, followed.is_following), (3, true));
+        assert_eq!(apply_follow(followed, true), followed);
+        assert_eq!(apply_follow(followed, false), info);
+    }
+
+    #[test]
+    fn feed_pages_and_days() {
+        let loaded = vec![activity(1, "2024-03-02T10:00:00Z")];
+        let page = vec![
+            activity(1, "2024-03-02T10:00:00Z"),
+            activity(2, "2024-03-02T08:00:00Z"),
+            activity(3, "garbled"),
+            activity(4, "2024-03-01T23:00:00Z"),
+        ];
+        let feed = append_feed_page(&loaded, page);
+        assert_eq!(feed.iter().map(|a| a.id).collect::<Vec<i64>>(), vec![1, 2, 3, 4]);
+
+        let days = group_feed_by_day(&feed)
+            .iter()
+            .map(|(d, items)| (*d, items.len()))
+            .collect::<Vec<(Option<NaiveDate>, usize)>>();
+        assert_eq!(
+            days,
+            vec![
+                (NaiveDate::from_ymd_opt(2024, 3, 2), 3),
+                (NaiveDate::from_ymd_opt(2024, 3, 1), 1),
+            ]
+        );
+    }
+}
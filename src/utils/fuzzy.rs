@@ -0,0 +1,196 @@
+use crate::prelude::*;
+
+/// Folds a title or search query into a comparable form: lowercase, punctuation
+/// dropped, and romanization variants of long vowels (ō, ou, oo, ū, uu) collapsed
+/// so that "Shōnen", "Shounen" and "shonen" all end up as `shonen`.
+pub fn normalize_title(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\'' | '’' | '`' => {}
+            'ā' | 'Ā' | 'â' | 'Â' => folded.push('a'),
+            'ē' | 'Ē' | 'ê' | 'Ê' => folded.push('e'),
+            'ī' | 'Ī' | 'î' | 'Î' => folded.push('i'),
+            'ō' | 'Ō' | 'ô' | 'Ô' => folded.push('o'),
+            'ū' | 'Ū' | 'û' | 'Û' => folded.push('u'),
+            c if c.is_alphanumeric() => folded.extend(c.to_lowercase()),
+            _ => folded.push(' '),
+        }
+    }
+
+    folded
+        .split_whitespace()
+        .map(|word| {
+            word.replace("ou", "o")
+                .replace("oo", "o")
+                .replace("uu", "u")
+                .replace("aa", "a")
+                .replace("ii", "i")
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<char>>();
+    let b = b.chars().collect::<Vec<char>>();
+    let mut prev = (0..=b.len()).collect::<Vec<usize>>();
+    let mut cur = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        cur[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            cur[j] = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+fn word_similarity(q: &str, t: &str) -> f64 {
+    if q == t {
+        return 1.0;
+    }
+
+    let q_len = q.chars().count();
+    if q_len >= 3 && t.starts_with(q) {
+        return 0.9;
+    }
+
+    let max_len = q_len.max(t.chars().count());
+    if max_len == 0 {
+        return 0.0;
+    }
+    let sim = 1.0 - edit_distance(q, t) as f64 / max_len as f64;
+
+    // Anything below this is more likely a different word than a typo
+    if sim >= 0.6 {
+        sim * 0.85
+    } else {
+        0.0
+    }
+}
+
+/// Scores how well `query` matches `title` between 0 and 1. Both are normalized
+/// first, so callers can pass raw user input and raw titles.
+pub fn title_similarity(query: &str, title: &str) -> f64 {
+    let q = normalize_title(query);
+    let t = normalize_title(title);
+    if q.is_empty() || t.is_empty() {
+        return 0.0;
+    }
+    if t == q {
+        return 1.0;
+    }
+    if t.contains(&q) || t.replace(' ', "").contains(&q.replace(' ', "")) {
+        return 0.95;
+    }
+
+    let t_words = t.split(' ').collect::<Vec<&str>>();
+    let q_words = q.split(' ').collect::<Vec<&str>>();
+    let coverage = q_words
+        .iter()
+        .map(|q_word| {
+            t_words
+                .iter()
+                .map(|t_word| word_similarity(q_word, t_word))
+                .fold(0.0, f64::max)
+        })
+        .sum::<f64>()
+        / q_words.len() as f64;
+
+    coverage * 0.9
+}
+
+/// Best match of `query` against every title variant of an anime, including
+/// synonyms and the Japanese/English titles.
+pub fn best_title_score(query: &str, ao: &AnimeObj) -> f64 {
+    ao.titles
+        .iter()
+        .map(|t| t.title.as_str())
+        .chain(ao.title_english.as_deref())
+        .chain(ao.title_japanese.as_deref())
+        .map(|t| title_similarity(query, t))
+        .fold(0.0, f64::max)
+}
+
+/// Re-ranks a page of results by how closely their titles match `query`.
+/// Scores are bucketed so that near-ties keep Jikan's original order.
+pub fn rank_by_titles(query: &str, v: &mut Vec<AnimeObj>) {
+    let mut scored = v
+        .drain(..)
+        .map(|ao| ((best_title_score(query, &ao) * 20.0).round() as i64, ao))
+        .collect::<Vec<(i64, AnimeObj)>>();
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    v.extend(scored.into_iter().map(|(_, ao)| ao));
+}
+
+/// Produces a looser query to retry with when the original one returns nothing.
+/// Keeps the longest word as typed, or shortens a single word so that typos near
+/// the end of it still hit. Returns `None` when there is nothing left to relax.
+///
+/// Unlike scoring, this works on the raw words: the result is sent to Jikan, which
+/// knows nothing about `normalize_title`'s folded spellings.
+pub fn relax_query(query: &str) -> Option<String> {
+    let words = query
+        .split(|c: char| !c.is_alphanumeric() && c != '\'' && c != '’')
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<&str>>();
+    let longest = words
+        .iter()
+        .fold(None, |best: Option<&str>, w| match best {
+            Some(b) if b.chars().count() >= w.chars().count() => Some(b),
+            _ => Some(w),
+        })?
+        .to_string();
+
+    let relaxed = if words.len() > 1 {
+        longest
+    } else {
+        let n = longest.chars().count();
+        let keep = ((n as f64) * 0.6).ceil() as usize;
+        if keep < 3 || keep >= n {
+            return None;
+        }
+        longest.chars().take(keep).collect::<String>()
+    };
+
+    if relaxed.chars().count() < 3 {
+        None
+    } else {
+        Some(relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(normalize_title("Frieren: Beyond Journey's End"), "frieren beyond jorneys end");
+        assert_eq!(normalize_title("Shōnen"), normalize_title("Shounen"));
+        assert_eq!(normalize_title("Shōnen"), normalize_title("shonen"));
+        assert_eq!(normalize_title("  Kimi no  Na wa. "), "kimi no na wa");
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert!(title_similarity("frieren beyond journeys end", "Frieren: Beyond Journey's End") > 0.9);
+        assert!(title_similarity("shingeki", "Shingeki no Kyojin") > 0.9);
+        assert!(title_similarity("shingeki no kyojn", "Shingeki no Kyojin") > 0.7);
+        assert!(title_similarity("kaguya", "Shingeki no Kyojin") < 0.3);
+    }
+
+    #[test]
+    fn test_relax_query() {
+        assert_eq!(relax_query("frieren beyond journeys end"), Some("journeys".to_string()));
+        assert_eq!(relax_query("Journey's End"), Some("Journey's".to_string()));
+        assert_eq!(relax_query("shingekki"), Some("shinge".to_string()));
+        assert_eq!(relax_query("shoujo"), Some("shou".to_string()));
+        assert_eq!(relax_query("ab"), None);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+/// Approximate widths of the sizes MAL serves, for `srcset` width descriptors.
+const SMALL_WIDTH: u32 = 100;
+const DEFAULT_WIDTH: u32 = 225;
+const LARGE_WIDTH: u32 = 450;
+
+pub async fn fetch_anime_pictures(mal_id: u64) -> Result<Vec<AnimeImages>, gloo::net::Error> {
+    force_req::<PicturesWrapper>(&format!("https://api.jikan.moe/v4/anime/{}/pictures", mal_id))
+        .await
+        .map(|wrapper| wrapper.data)
+}
+
+pub async fn fetch_character_pictures(char_id: u64) -> Result<Vec<AnimeImages>, gloo::net::Error> {
+    force_req::<PicturesWrapper>(&format!("https://api.jikan.moe/v4/characters/{}/pictures", char_id))
+        .await
+        .map(|wrapper| wrapper.data)
+}
+
+/// `srcset` of the sizes `images` has, smallest first. Empty when it has none.
+pub fn image_srcset(images: &Images) -> String {
+    [
+        (&images.small_image_url, SMALL_WIDTH),
+        (&images.image_url, DEFAULT_WIDTH),
+        (&images.large_image_url, LARGE_WIDTH),
+    ]
+    .iter()
+    .filter_map(|(url, w)| url.as_ref().map(|url| format!("{} {}w", url, w)))
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+/// Largest picture available, preferring WebP.
+pub fn largest_image(images: &AnimeImages) -> Option<String> {
+    [&images.webp, &images.jpg]
+        .iter()
+        .find_map(|i| i.large_image_url.clone().or(i.image_url.clone()).or(i.small_image_url.clone()))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightboxKey {
+    Previous,
+    Next,
+    Close,
+}
+
+impl LightboxKey {
+    /// The lightbox action of a `KeyboardEvent.key`, if it has one.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowLeft" => Some(LightboxKey::Previous),
+            "ArrowRight" => Some(LightboxKey::Next),
+            "Escape" | "Esc" => Some(LightboxKey::Close),
+            _ => None,
+        }
+    }
+}
+
+/// Picture shown after stepping `delta` from `index`, wrapping around either end.
+pub fn lightbox_step(index: usize, len: usize, delta: isize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (index as isize + delta).rem_euclid(len as isize) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn images(small: Option<&str>, default: Option<&str>, large: Option<&str>) -> Images {
+        Images {
+            small_image_url: small.map(String::from),
+            image_url: default.map(String::from),
+            large_image_url: large.map(String::from),
+        }
+    }
+
+    #[test]
+    fn srcsets() {
+        assert_eq!(
+            image_srcset(&images(Some("s.webp"), Some("m.webp"), Some("l.webp"))),
+            "s.webp 100w, m.webp 225w, l.webp 450w"
+        );
+        assert_eq!(image_srcset(&images(None, Some("m.jpg"), None)), "m.jpg 225w");
+        assert_eq!(image_srcset(&images(None, None, None)), "");
+    }
+
+    #[test]
+    fn largest_prefers_webp() {
+        let pictures = AnimeImages {
+            jpg: images(None, Some("m.jpg"), Some("l.jpg")),
+            webp: images(Some("s.webp"), Some("m.webp"), None),
+        };
+        assert_eq!(largest_image(&pictures), Some("m.webp".to_string()));
+        assert_eq!(largest_image(&AnimeImages::default()), None);
+    }
+
+    #[test]
+    fn lightbox_navigation() {
+        assert_eq!(LightboxKey::from_key("ArrowRight"), Some(LightboxKey::Next));
+        assert_eq!(LightboxKey::from_key("Escape"), Some(LightboxKey::Close));
+        assert_eq!(LightboxKey::from_key("a"), None);
+        assert_eq!(lightbox_step(0, 5, -1), 4);
+        assert_eq!(lightbox_step(4, 5, 1), 0);
+        assert_eq!(lightbox_step(2, 5, 1), 3);
+        assert_eq!(lightbox_step(0, 0, 1), 0);
+    }
+}
@@ -3,6 +3,8 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
 
+use crate::utils::t;
+
 pub fn handle_season(month: &str) -> &'static str {
     let month = month.parse::<u8>().unwrap();
     if month == 1 || month == 2 || month == 3 {
@@ -219,40 +221,150 @@ pub async fn get_torrents(
 //     }
 // }
 
-pub fn verify_password(p1: &str, p2: &str) -> bool {
-    if p1 != p2 {
-        return false;
-    } else if p1.len() < 8 {
-        return false;
-    } else {
-        let mut n = 0;
-        let mut l = 0;
-        let mut s = 0;
-        let mut u = 0;
-        for c in p1.chars() {
-            if (!c.is_ascii_lowercase())
-                && (!c.is_ascii_uppercase())
-                && (!c.is_ascii_digit())
-                && (!c.is_ascii_punctuation())
-            {
-                return false;
-            } else if c.is_lowercase() {
-                l += 1;
-            } else if c.is_numeric() {
-                n += 1;
-            } else if c.is_uppercase() {
-                u += 1;
-            } else if c.is_ascii_punctuation() {
-                s += 1
-            }
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PasswordRule {
+    MinLength,
+    Uppercase,
+    Lowercase,
+    Number,
+    Symbol,
+    LatinOnly,
+}
+
+impl PasswordRule {
+    pub const ALL: [PasswordRule; 6] = [
+        PasswordRule::MinLength,
+        PasswordRule::Uppercase,
+        PasswordRule::Lowercase,
+        PasswordRule::Number,
+        PasswordRule::Symbol,
+        PasswordRule::LatinOnly,
+    ];
+
+    pub fn description(&self) -> String {
+        match self {
+            PasswordRule::MinLength => t!("auth.rule.min_length"),
+            PasswordRule::Uppercase => t!("auth.rule.uppercase"),
+            PasswordRule::Lowercase => t!("auth.rule.lowercase"),
+            PasswordRule::Number => t!("auth.rule.number"),
+            PasswordRule::Symbol => t!("auth.rule.symbol"),
+            PasswordRule::LatinOnly => t!("auth.rule.latin_only"),
         }
+    }
+
+    pub fn is_met(&self, p: &str) -> bool {
+        match self {
+            PasswordRule::MinLength => p.len() >= 8,
+            PasswordRule::Uppercase => p.chars().any(|c| c.is_ascii_uppercase()),
+            PasswordRule::Lowercase => p.chars().any(|c| c.is_ascii_lowercase()),
+            PasswordRule::Number => p.chars().any(|c| c.is_ascii_digit()),
+            PasswordRule::Symbol => p.chars().any(|c| c.is_ascii_punctuation()),
+            PasswordRule::LatinOnly => p.chars().all(|c| {
+                c.is_ascii_lowercase()
+                    || c.is_ascii_uppercase()
+                    || c.is_ascii_digit()
+                    || c.is_ascii_punctuation()
+            }),
+        }
+    }
+}
+
+pub fn verify_password(p1: &str, p2: &str) -> bool {
+    p1 == p2 && PasswordRule::ALL.iter().all(|rule| rule.is_met(p1))
+}
 
-        return n > 0 && l > 0 && s > 0 && u > 0;
+/// Client-side username check for instant feedback while typing. The backend has
+/// the final say when the account is created.
+pub fn validate_username(username: &str) -> Result<(), String> {
+    let n = username.chars().count();
+    if n < 3 {
+        Err(t!("auth.username_too_short"))
+    } else if n > 20 {
+        Err(t!("auth.username_too_long"))
+    } else if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        Err(t!("auth.username_charset"))
+    } else {
+        Ok(())
     }
 }
 
+/// MAL id of the anime a shared MyAnimeList link points to, e.g.
+/// `https://myanimelist.net/anime/5114/Fullmetal_Alchemist` or `.../anime.php?id=5114`.
+pub fn mal_anime_id(shared: &str) -> Option<u64> {
+    let (_, rest) = shared.split_once("myanimelist.net/anime")?;
+    let rest = rest
+        .strip_prefix('/')
+        .or_else(|| rest.strip_prefix(".php?id="))?;
+    let digits = rest
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect::<String>();
+    digits.parse::<u64>().ok()
+}
+
 pub fn cur_year() -> u32 {
     let dt = chrono::Utc::now();
     let s = format!("{}", dt.format("%Y"));
     s.parse::<u32>().unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mal_anime_id() {
+        assert_eq!(
+            mal_anime_id("https://myanimelist.net/anime/5114/Fullmetal_Alchemist__Brotherhood"),
+            Some(5114)
+        );
+        assert_eq!(
+            mal_anime_id("Check this out! https://myanimelist.net/anime.php?id=21"),
+            Some(21)
+        );
+        assert_eq!(mal_anime_id("https://myanimelist.net/manga/2"), None);
+        assert_eq!(mal_anime_id("https://myanimelist.net/anime/"), None);
+        assert_eq!(mal_anime_id("Frieren"), None);
+    }
+
+    #[test]
+    fn test_password_rules() {
+        let unmet = |p: &str| {
+            PasswordRule::ALL
+                .iter()
+                .filter(|rule| !rule.is_met(p))
+                .copied()
+                .collect::<Vec<PasswordRule>>()
+        };
+        assert_eq!(unmet("Aninfo#2024"), vec![]);
+        assert_eq!(unmet("Ab1!"), vec![PasswordRule::MinLength]);
+        assert_eq!(
+            unmet("aninfo2024"),
+            vec![PasswordRule::Uppercase, PasswordRule::Symbol]
+        );
+        assert_eq!(unmet("ANINFO#abc"), vec![PasswordRule::Number]);
+        assert_eq!(unmet("Aninfo#2024 "), vec![PasswordRule::LatinOnly]);
+        assert_eq!(unmet("Anïnfo#2024"), vec![PasswordRule::LatinOnly]);
+    }
+
+    #[test]
+    fn test_verify_password() {
+        assert!(verify_password("Aninfo#2024", "Aninfo#2024"));
+        assert!(!verify_password("Aninfo#2024", "Aninfo#2025"));
+        assert!(!verify_password("aninfo", "aninfo"));
+    }
+
+    #[test]
+    fn test_validate_username() {
+        assert!(validate_username("shinji_01").is_ok());
+        assert!(validate_username("a-b").is_ok());
+        assert!(validate_username("ab").is_err());
+        assert!(validate_username(&"a".repeat(21)).is_err());
+        assert!(validate_username(&"a".repeat(20)).is_ok());
+        assert!(validate_username("two words").is_err());
+        assert!(validate_username("名前です").is_err());
+    }
+}
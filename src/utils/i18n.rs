@@ -0,0 +1,208 @@
+use std::{cell::Cell, collections::HashMap, sync::OnceLock};
+
+use chrono::NaiveDate;
+use serde_json::Value;
+
+/// UI message catalogs, one flat JSON object per locale. Adding a locale means adding
+/// a file here and a `Language` variant pointing at it; components only ever use keys.
+///
+/// Values are either a plain string or, for messages depending on a `count`, an
+/// object of plural categories (`"one"`, `"other"`). `{name}` placeholders are
+/// filled from the arguments of `t!`. Keys starting with `_` hold locale data used
+/// by the formatting helpers below.
+const CATALOG_SOURCES: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.json")),
+    ("ja", include_str!("../../locales/ja.json")),
+];
+
+pub const FALLBACK_LOCALE: &str = "en";
+
+type Catalog = HashMap<String, Value>;
+
+thread_local! {
+    static CURRENT_LOCALE: Cell<&'static str> = Cell::new(FALLBACK_LOCALE);
+}
+
+fn catalogs() -> &'static HashMap<&'static str, Catalog> {
+    static CATALOGS: OnceLock<HashMap<&'static str, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        CATALOG_SOURCES
+            .iter()
+            .map(|(locale, src)| {
+                let catalog = serde_json::from_str::<Catalog>(src)
+                    .unwrap_or_else(|e| panic!("Invalid message catalog {}: {}", locale, e));
+                (*locale, catalog)
+            })
+            .collect()
+    })
+}
+
+/// Locales that have a catalog, e.g. for a language picker.
+pub fn available_locales() -> Vec<&'static str> {
+    CATALOG_SOURCES.iter().map(|(locale, _)| *locale).collect()
+}
+
+/// Sets the locale `t!` translates into. `AppContextProvider` keeps this in sync with
+/// `AppCtx.language` before its children render.
+pub fn set_locale(locale: &'static str) {
+    CURRENT_LOCALE.with(|l| l.set(locale));
+}
+
+pub fn current_locale() -> &'static str {
+    CURRENT_LOCALE.with(|l| l.get())
+}
+
+fn lookup(locale: &str, key: &str) -> Option<&'static Value> {
+    let catalogs = catalogs();
+    catalogs
+        .get(locale)
+        .and_then(|c| c.get(key))
+        .or_else(|| catalogs.get(FALLBACK_LOCALE).and_then(|c| c.get(key)))
+}
+
+fn locale_data(locale: &str, key: &str) -> Option<&'static str> {
+    lookup(locale, key).and_then(|v| v.as_str())
+}
+
+/// CLDR-style plural category for `n`. Which categories a locale distinguishes is
+/// catalog data (`_plural`), so a new locale does not need code for it.
+pub fn plural_category(locale: &str, n: f64) -> &'static str {
+    match locale_data(locale, "_plural") {
+        Some("other") => "other",
+        _ if n == 1.0 => "one",
+        _ => "other",
+    }
+}
+
+fn interpolate(template: &str, args: &[(&str, String)]) -> String {
+    args.iter().fold(template.to_string(), |s, (name, value)| {
+        s.replace(&format!("{{{}}}", name), value)
+    })
+}
+
+/// The message for `key` in `locale`, falling back to English and then to the key
+/// itself so a missing translation is visible but never breaks the page.
+pub fn translate_in(locale: &str, key: &str, args: &[(&str, String)]) -> String {
+    let count = args
+        .iter()
+        .find(|(name, _)| *name == "count")
+        .and_then(|(_, v)| v.parse::<f64>().ok());
+
+    let template = match (lookup(locale, key), count) {
+        (Some(Value::String(s)), _) => s.as_str(),
+        (Some(Value::Object(forms)), Some(n)) => forms
+            .get(plural_category(locale, n))
+            .or_else(|| forms.get("other"))
+            .and_then(|v| v.as_str())
+            .unwrap_or(key),
+        (Some(Value::Object(forms)), None) => forms
+            .get("other")
+            .and_then(|v| v.as_str())
+            .unwrap_or(key),
+        _ => key,
+    };
+
+    // Counts read better with digit grouping
+    let args = args
+        .iter()
+        .map(|(name, value)| match (*name, count) {
+            ("count", Some(n)) if n.fract() == 0.0 => (*name, format_number_in(locale, n, 0)),
+            _ => (*name, value.clone()),
+        })
+        .collect::<Vec<(&str, String)>>();
+
+    interpolate(template, &args)
+}
+
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+    translate_in(current_locale(), key, args)
+}
+
+/// `n` with the locale's digit grouping and decimal separator.
+pub fn format_number_in(locale: &str, n: f64, decimals: usize) -> String {
+    let group = locale_data(locale, "_number_group").unwrap_or(",");
+    let decimal = locale_data(locale, "_number_decimal").unwrap_or(".");
+
+    let formatted = format!("{:.*}", decimals, n.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((i, f)) => (i.to_string(), Some(f.to_string())),
+        None => (formatted, None),
+    };
+
+    let digits = int_part.chars().collect::<Vec<char>>();
+    let mut grouped = String::new();
+    for (i, c) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push_str(group);
+        }
+        grouped.push(*c);
+    }
+
+    let sign = if n < 0.0 && formatted_is_nonzero(&digits, &frac_part) { "-" } else { "" };
+    match frac_part {
+        Some(f) => format!("{}{}{}{}", sign, grouped, decimal, f),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
+fn formatted_is_nonzero(int_digits: &[char], frac: &Option<String>) -> bool {
+    int_digits.iter().any(|c| *c != '0')
+        || frac.as_ref().map_or(false, |f| f.chars().any(|c| c != '0'))
+}
+
+pub fn format_number(n: f64, decimals: usize) -> String {
+    format_number_in(current_locale(), n, decimals)
+}
+
+/// A calendar date in the locale's long format (`_date_long`, a chrono pattern).
+pub fn format_date_in(locale: &str, date: &NaiveDate) -> String {
+    let pattern = locale_data(locale, "_date_long").unwrap_or("%B %-d, %Y");
+    date.format(pattern).to_string()
+}
+
+pub fn format_date(date: &NaiveDate) -> String {
+    format_date_in(current_locale(), date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_locale_has_every_key() {
+        let catalogs = catalogs();
+        let en = catalogs.get(FALLBACK_LOCALE).unwrap();
+        for (locale, catalog) in catalogs.iter() {
+            for key in en.keys() {
+                assert!(catalog.contains_key(key), "{} is missing {}", locale, key);
+            }
+        }
+    }
+
+    #[test]
+    fn plurals_and_placeholders() {
+        let count = |n: u32| [("count", n.to_string())];
+        assert_eq!(translate_in("en", "stats.episodes", &count(1)), "Episode in these anime");
+        assert_eq!(translate_in("en", "stats.episodes", &count(2)), "Episodes in these anime");
+        assert_eq!(
+            translate_in("en", "comments.count", &count(1200)),
+            "1,200 comments"
+        );
+        assert_eq!(translate_in("ja", "comments.count", &count(1)), "1件のコメント");
+    }
+
+    #[test]
+    fn missing_keys_fall_back() {
+        assert_eq!(translate_in("xx", "nav.login", &[]), "Login");
+        assert_eq!(translate_in("ja", "no.such.key", &[]), "no.such.key");
+    }
+
+    #[test]
+    fn numbers_and_dates() {
+        assert_eq!(format_number_in("en", 1234567.0, 0), "1,234,567");
+        assert_eq!(format_number_in("en", 1234.56, 1), "1,234.6");
+        assert_eq!(format_number_in("en", -0.01, 1), "0.0");
+        let date = NaiveDate::from_ymd_opt(2024, 4, 7).unwrap();
+        assert_eq!(format_date_in("ja", &date), "2024年4月7日");
+    }
+}
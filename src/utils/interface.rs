@@ -1,37 +1,34 @@
 use crate::prelude::*;
 
+/// Icon of the theme the toggle switches to next.
 pub fn handle_theme_icon(cx: &AppContext) -> &'static str {
-    if let Theme::Light = ((*cx).theme).clone() {
-        "./static/moon.svg"
-    } else if let Theme::Dark = ((*cx).theme).clone() {
-        "./static/light.svg"
-    } else {
-        ""
+    match (*cx).theme.next() {
+        Theme::Dark => "./static/moon.svg",
+        Theme::Auto => "./static/auto.svg",
+        Theme::Light => "./static/light.svg",
     }
 }
 
-pub fn handle_title_language(cx: &AppContext, ao: &AnimeObj) -> String {
-    match (*cx).get_langauge() {
-        Language::EN => (*ao)
-            .title_english
-            .clone()
-            .unwrap_or((*ao).titles[0].title.clone()),
-        Language::JP => (*ao).title_japanese.clone().unwrap_or(
-            (*ao)
-                .title_english
-                .clone()
-                .unwrap_or((*ao).titles[0].title.clone()),
-        ),
-    }
+/// Moves the theme one step along Light → Dark → Auto.
+pub fn cycle_theme(cx: &AppContext) {
+    cx.dispatch((*cx).update_theme_into((*cx).theme.next()).into());
 }
 
-pub fn handle_eps_language(cx: &AppContext, eo: &AnimeEpisode) -> String {
-    match &(*cx).language {
-        &Language::EN => (*eo).title.clone(),
-        &Language::JP => (*eo)
-            .title_japanese
-            .as_ref()
-            .unwrap_or(&(*eo).title)
-            .to_string(),
-    }
+/// Offers `value` to the user as a pretty-printed JSON file download.
+pub fn download_json<T: Serialize>(filename: &str, value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    let href = format!(
+        "data:application/json;charset=utf-8,{}",
+        String::from(web_sys::js_sys::encode_uri_component(&json))
+    );
+
+    let document = gloo::utils::document();
+    let a = document
+        .create_element("a")
+        .map_err(|_| "Cannot create download link.".to_string())?;
+    a.set_attribute("href", &href)
+        .and_then(|_| a.set_attribute("download", filename))
+        .map_err(|_| "Cannot create download link.".to_string())?;
+    a.unchecked_into::<web_sys::HtmlElement>().click();
+    Ok(())
 }
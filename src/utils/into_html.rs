@@ -8,7 +8,7 @@ pub fn into_anime_cards(v: &Vec<AnimeObj>) -> Html {
 
 pub fn into_anime_cards_minimal(v: &Vec<AnimeRecWrapper>) -> Html {
     if v.is_empty() {
-        return html! {<span class="no-result">{"There are no recommended anime."}</span>};
+        return html! {<span class="no-result">{t!("details.no_recommendations")}</span>};
     }
     html! {
         <div id="ani-rec-card-wrapper" class="ani-rec-card-wrapper">
@@ -20,9 +20,22 @@ pub fn into_anime_cards_minimal(v: &Vec<AnimeRecWrapper>) -> Html {
     }
 }
 
+/// Cards for anime known only from stored or account data, e.g. a user's list.
+pub fn into_saved_anime_cards(cards: Vec<AnimeRecObj>) -> Html {
+    html! {
+        <div class="cards-wrapper b-y">
+        {
+            cards.into_iter().map(|ao| html! {
+                <AnimeCardMinimal {ao}/>
+            }).collect::<Html>()
+        }
+        </div>
+    }
+}
+
 pub fn into_char_cards(v: &Vec<Char>) -> Html {
     if v.is_empty() {
-        "Character data is currently unavailable.".into()
+        t!("details.characters_unavailable").into()
     } else {
         v.into_iter()
             .map(|char| html! (<CharacterCard char={char.clone()}/>))
@@ -32,7 +45,7 @@ pub fn into_char_cards(v: &Vec<Char>) -> Html {
 
 pub fn into_eps_cards(cx: &AppContext, v: &Vec<AnimeEpisode>, ao: AnimeObj, full: bool) -> Html {
     if v.is_empty() {
-        return "Episodes data is currently unavailable.".into();
+        return t!("details.episodes_unavailable").into();
     }
     let mut m = 0;
     let theme = handle_theme(cx);
@@ -77,9 +90,9 @@ pub fn into_eps_cards(cx: &AppContext, v: &Vec<AnimeEpisode>, ao: AnimeObj, full
                     <div class="eps-info">
                         <div class="eps-no">{m}</div>
                         {if let Some(ref url) = eo.url {
-                            html!{<span class="eps-title cursor-arrow"><a href={(*url).clone()}>{handle_eps_language(&cx, eo)}</a></span>}
+                            html!{<span class="eps-title cursor-arrow"><a href={(*url).clone()}>{display_episode_title(&cx, eo)}</a></span>}
                         } else {
-                            html!{<span class="eps-title cursor-arrow">{handle_eps_language(&cx, eo)}</span>}
+                            html!{<span class="eps-title cursor-arrow">{display_episode_title(&cx, eo)}</span>}
                         }
                         }
                     </div>
@@ -174,7 +187,7 @@ pub fn into_page_btns(
                     html! {
                         <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", theme)} onclick={move |_: MouseEvent| {
                             log!("btn pushed");
-                            cx.dispatch((*cx).update_page_into(n as u8));
+                            cx.dispatch((*cx).update_page_into(n as u8).into());
                             web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
                             nav.push(&Route::SearchResult {q: q.clone(), page: n as u32})
                         }}>{n}</button>
@@ -183,7 +196,7 @@ pub fn into_page_btns(
                     html! {
                         <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
                             log!("btn pushed");
-                            cx.dispatch((*cx).update_page_into(n as u8));
+                            cx.dispatch((*cx).update_page_into(n as u8).into());
                             web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
                             nav.push(&Route::SearchResult {q: q.clone(), page: n as u32})
                         }}>{n}</button>
@@ -221,7 +234,7 @@ pub fn into_page_btns_std(
                     html! {
                         <button id="page-btn-selected" class={format!("cursor-pointer page-btn selected {}", theme)} onclick={move |_: MouseEvent| {
                             log!("btn pushed");
-                            cx.dispatch((*cx).update_page_into(n as u8));
+                            cx.dispatch((*cx).update_page_into(n as u8).into());
                             web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
                             nav.push(&Route::AnimeResultStd { content_title: title.clone(), page: n as u32, url: url.clone() } )
                         }}>{n}</button>
@@ -230,7 +243,7 @@ pub fn into_page_btns_std(
                     html! {
                         <button class={format!("cursor-pointer page-btn {}", theme)} onclick={move |_: MouseEvent| {
                             log!("btn pushed");
-                            cx.dispatch((*cx).update_page_into(n as u8));
+                            cx.dispatch((*cx).update_page_into(n as u8).into());
                             web_sys::window().expect("Missing window.").scroll_to_with_x_and_y(0f64, 0f64);
                             nav.push(&Route::AnimeResultStd { content_title: title.clone(), page: n as u32, url: url.clone() } )
                         }}>{n}</button>
@@ -245,6 +258,28 @@ pub fn into_comment_cards() -> Html {
     todo!()
 }
 
+/// Comment markdown as elements. Text only ever becomes text nodes, and links only
+/// carry hrefs `parse_markdown` has already checked.
+pub fn into_markdown_html(nodes: &[MdNode]) -> Html {
+    nodes
+        .iter()
+        .map(|node| match node {
+            MdNode::Text(s) => html! {<>{s}</>},
+            MdNode::Bold(inner) => html! {<strong>{into_markdown_html(inner)}</strong>},
+            MdNode::Italic(inner) => html! {<em>{into_markdown_html(inner)}</em>},
+            MdNode::Spoiler(inner) => html! {<MdSpoiler>{into_markdown_html(inner)}</MdSpoiler>},
+            MdNode::Link { text, href } => html! {
+                <a href={href.clone()} target="_blank" rel="noopener noreferrer nofollow ugc">{text}</a>
+            },
+            MdNode::LineBreak => html! {<br/>},
+        })
+        .collect::<Html>()
+}
+
+pub fn render_markdown(src: &str) -> Html {
+    into_markdown_html(&parse_markdown(src))
+}
+
 // pub handle_studios_universal(studios: Vec<MALObj>, class: String) -> Html {
 //     let n = studios.len();
 //     let mut m = 1;
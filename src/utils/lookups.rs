@@ -0,0 +1,70 @@
+use gloo::storage::{SessionStorage, Storage};
+use serde::de::DeserializeOwned;
+use std::{cell::Cell, fmt::Debug};
+use web_sys::js_sys::Date;
+
+use crate::prelude::*;
+
+const ANIME_LOOKUP_PREFIX: &str = "aninfo-anime:";
+const RECS_LOOKUP_PREFIX: &str = "aninfo-recs:";
+/// Jikan allows 3 requests a second, so lookups start at least this far apart.
+const JIKAN_INTERVAL_MS: f64 = 350.0;
+
+thread_local! {
+    static NEXT_JIKAN_SLOT: Cell<f64> = Cell::new(0.0);
+}
+
+/// Waits for the next free request slot. Slots are handed out when asked for, so
+/// lookups running side by side queue up instead of bursting.
+async fn jikan_slot() {
+    let now = Date::now();
+    let slot = NEXT_JIKAN_SLOT.with(|next| {
+        let slot = next.get().max(now);
+        next.set(slot + JIKAN_INTERVAL_MS);
+        slot
+    });
+    if slot > now {
+        gloo::timers::future::TimeoutFuture::new((slot - now) as u32).await;
+    }
+}
+
+/// `force_req` that keeps the response in session storage under `key`, for lookups
+/// pages repeat on every mount. Failures are never cached, and requests that do go
+/// out are paced to Jikan's rate limit.
+async fn session_cached<T: Serialize + DeserializeOwned + Debug>(
+    key: &str,
+    url: &str,
+) -> Result<T, gloo::net::Error> {
+    if let Ok(hit) = SessionStorage::get::<T>(key) {
+        return Ok(hit);
+    }
+    jikan_slot().await;
+    let fetched = force_req::<T>(url).await?;
+    // A full storage only costs the next visit a request
+    if let Err(e) = SessionStorage::set(key, &fetched) {
+        log!(format!("{:?}", e));
+    }
+    Ok(fetched)
+}
+
+/// `/anime/{mal_id}`, fetched at most once per session.
+pub async fn lookup_anime(mal_id: i32) -> Result<AnimeObj, gloo::net::Error> {
+    session_cached::<AnimeObjAsQueryResult>(
+        &format!("{}{}", ANIME_LOOKUP_PREFIX, mal_id),
+        &format!("{}/{}", JIKAN_URL, mal_id),
+    )
+    .await
+    .map(|r| r.data)
+}
+
+/// `/anime/{mal_id}/recommendations`, fetched at most once per session.
+pub async fn lookup_recommendations(
+    mal_id: i32,
+) -> Result<Vec<AnimeRecWrapper>, gloo::net::Error> {
+    session_cached::<AnimeRecResult>(
+        &format!("{}{}", RECS_LOOKUP_PREFIX, mal_id),
+        &format!("{}/{}/recommendations", JIKAN_URL, mal_id),
+    )
+    .await
+    .map(|r| r.data)
+}
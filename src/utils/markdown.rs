@@ -0,0 +1,217 @@
+/// The markdown subset comments may use: `**bold**`, `*italics*` (or `_italics_`),
+/// `[links](https://…)` and `||spoilers||`. Anything else, HTML included, stays
+/// plain text.
+///
+/// Comments are parsed into these nodes instead of being turned into an HTML string,
+/// so text only ever reaches `html!` as text nodes and there is nothing to escape.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MdNode {
+    Text(String),
+    Bold(Vec<MdNode>),
+    Italic(Vec<MdNode>),
+    Spoiler(Vec<MdNode>),
+    Link { text: String, href: String },
+    LineBreak,
+}
+
+const ESCAPABLE: &[char] = &['\\', '*', '_', '[', ']', '(', ')', '|'];
+
+pub fn parse_markdown(src: &str) -> Vec<MdNode> {
+    let src = src.replace("\r\n", "\n");
+    parse_inline(src.trim())
+}
+
+fn parse_inline(src: &str) -> Vec<MdNode> {
+    let mut nodes = vec![];
+    let mut text = String::new();
+    let mut i = 0;
+
+    while let Some(c) = src[i..].chars().next() {
+        let rest = &src[i..];
+        let parsed = match c {
+            '*' if rest.starts_with("**") => {
+                delimited(rest, "**").map(|(inner, len)| (MdNode::Bold(parse_inline(inner)), len))
+            }
+            '|' if rest.starts_with("||") => delimited(rest, "||")
+                .map(|(inner, len)| (MdNode::Spoiler(parse_inline(inner)), len)),
+            // `_` inside a word, as in snake_case, is not emphasis
+            '_' if src[..i].chars().last().map_or(false, |p| p.is_alphanumeric()) => None,
+            '*' | '_' => delimited(rest, &rest[..1])
+                .map(|(inner, len)| (MdNode::Italic(parse_inline(inner)), len)),
+            '[' => link(rest),
+            '\n' => Some((MdNode::LineBreak, 1)),
+            _ => None,
+        };
+
+        if let Some((node, len)) = parsed {
+            if !text.is_empty() {
+                nodes.push(MdNode::Text(std::mem::take(&mut text)));
+            }
+            nodes.push(node);
+            i += len;
+            continue;
+        }
+
+        match rest[c.len_utf8()..].chars().next() {
+            Some(next) if c == '\\' && ESCAPABLE.contains(&next) => {
+                text.push(next);
+                i += 1 + next.len_utf8();
+            }
+            _ => {
+                text.push(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        nodes.push(MdNode::Text(text));
+    }
+    nodes
+}
+
+/// Inner text of a span opening with `delim` at the start of `src`, and the byte
+/// length of the whole span. Empty spans or ones starting with whitespace are not
+/// markup, so `2 * 3 * 4` stays as it is.
+fn delimited<'a>(src: &'a str, delim: &str) -> Option<(&'a str, usize)> {
+    let body = &src[delim.len()..];
+    if body.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let end = body.find(delim)?;
+    let inner = &body[..end];
+    if inner.is_empty() || inner.ends_with(char::is_whitespace) || inner.contains('\n') {
+        return None;
+    }
+    Some((inner, delim.len() * 2 + end))
+}
+
+/// `[text](href)` at the start of `src`. Links with an unsafe target are left as
+/// plain text.
+fn link(src: &str) -> Option<(MdNode, usize)> {
+    let close = src.find("](")?;
+    let text = &src[1..close];
+    let href_len = src[close + 2..].find(')')?;
+    let href = &src[close + 2..close + 2 + href_len];
+    if text.trim().is_empty() || text.contains('\n') {
+        return None;
+    }
+    let href = safe_href(href)?;
+    Some((
+        MdNode::Link {
+            text: text.to_string(),
+            href,
+        },
+        close + 3 + href_len,
+    ))
+}
+
+/// Only absolute http(s) URLs are linked; `javascript:`, `data:` and the like never
+/// make it into an `href`.
+pub fn safe_href(href: &str) -> Option<String> {
+    let href = href.trim();
+    let lower = href.to_ascii_lowercase();
+    let allowed = lower.starts_with("https://") || lower.starts_with("http://");
+    let clean = !href
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '<' | '>' | '`'));
+    (allowed && clean && href.len() > "https://".len()).then(|| href.to_string())
+}
+
+/// The comment as plain text, for previews and notifications.
+pub fn markdown_plain_text(nodes: &[MdNode]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            MdNode::Text(s) => s.clone(),
+            MdNode::Bold(inner) | MdNode::Italic(inner) | MdNode::Spoiler(inner) => {
+                markdown_plain_text(inner)
+            }
+            MdNode::Link { text, .. } => text.clone(),
+            MdNode::LineBreak => " ".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> MdNode {
+        MdNode::Text(s.to_string())
+    }
+
+    #[test]
+    fn emphasis_and_spoilers() {
+        assert_eq!(
+            parse_markdown("a **bold** and *it* ||end||"),
+            vec![
+                text("a "),
+                MdNode::Bold(vec![text("bold")]),
+                text(" and "),
+                MdNode::Italic(vec![text("it")]),
+                text(" "),
+                MdNode::Spoiler(vec![text("end")]),
+            ]
+        );
+        assert_eq!(
+            parse_markdown("**a _b_**"),
+            vec![MdNode::Bold(vec![text("a "), MdNode::Italic(vec![text("b")])])]
+        );
+    }
+
+    #[test]
+    fn unmatched_or_spaced_markers_stay_text() {
+        assert_eq!(parse_markdown("2 * 3 * 4"), vec![text("2 * 3 * 4")]);
+        assert_eq!(parse_markdown("**open"), vec![text("**open")]);
+        assert_eq!(parse_markdown("snake_case_name"), vec![text("snake_case_name")]);
+        assert_eq!(parse_markdown(r"\*not\*"), vec![text("*not*")]);
+    }
+
+    #[test]
+    fn multi_byte_text() {
+        assert_eq!(parse_markdown("進撃の巨人"), vec![text("進撃の巨人")]);
+        assert_eq!(
+            parse_markdown("café **最高** 🎉"),
+            vec![
+                text("café "),
+                MdNode::Bold(vec![text("最高")]),
+                text(" 🎉"),
+            ]
+        );
+        assert_eq!(parse_markdown(r"é\*x\*"), vec![text("é*x*")]);
+    }
+
+    #[test]
+    fn html_is_just_text() {
+        let src = "<img src=x onerror=alert(1)>";
+        assert_eq!(parse_markdown(src), vec![text(src)]);
+    }
+
+    #[test]
+    fn only_http_links() {
+        assert_eq!(
+            parse_markdown("[MAL](https://myanimelist.net)"),
+            vec![MdNode::Link {
+                text: "MAL".to_string(),
+                href: "https://myanimelist.net".to_string(),
+            }]
+        );
+        assert_eq!(
+            parse_markdown("[x](javascript:alert(1))"),
+            vec![text("[x](javascript:alert(1))")]
+        );
+        assert_eq!(safe_href("https://a.b/\" onclick=\"x"), None);
+        assert_eq!(safe_href("data:text/html,hi"), None);
+    }
+
+    #[test]
+    fn line_breaks_and_plain_text() {
+        let nodes = parse_markdown("one\r\n**two**");
+        assert_eq!(
+            nodes,
+            vec![text("one"), MdNode::LineBreak, MdNode::Bold(vec![text("two")])]
+        );
+        assert_eq!(markdown_plain_text(&nodes), "one two");
+    }
+}
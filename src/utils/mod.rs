@@ -1,6 +1,24 @@
 pub mod app_macros;
+pub mod charts;
+pub mod comments;
 pub mod data_handling;
+pub mod episodes;
+pub mod follows;
+pub mod fuzzy;
+pub mod gallery;
 pub mod general;
+pub mod i18n;
 pub mod interface;
 pub mod into_html;
-pub(crate) use app_macros::get_elem_by_id;
+pub mod lookups;
+pub mod markdown;
+pub mod moderation;
+pub mod offline;
+pub mod profiles;
+pub mod progress;
+pub mod recommend;
+pub mod session;
+pub mod settings;
+pub mod stats;
+pub mod titles;
+pub(crate) use app_macros::{get_elem_by_id, t};
@@ -0,0 +1,128 @@
+use std::cell::Cell;
+
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::js_sys::Date;
+
+use crate::prelude::*;
+
+const BLOCKED_USERS_KEY: &str = "aninfo-blocked-users";
+/// Minimum time between two posted comments.
+pub const POST_COOLDOWN_MS: f64 = 30_000.0;
+/// Wait after a 429 that does not say how long to wait.
+const DEFAULT_RETRY_AFTER_SECS: u32 = 60;
+
+thread_local! {
+    /// Milliseconds since epoch before which no comment is sent.
+    static POST_COOLDOWN_UNTIL: Cell<f64> = Cell::new(0.0);
+}
+
+pub fn load_blocked_users() -> Vec<String> {
+    LocalStorage::get::<Vec<String>>(BLOCKED_USERS_KEY).unwrap_or_default()
+}
+
+pub fn save_blocked_users(usernames: &[String]) {
+    if let Err(e) = LocalStorage::set(BLOCKED_USERS_KEY, usernames) {
+        log!(format!("{:?}", e));
+    }
+}
+
+/// Whole seconds left until `until`, `None` once it has passed.
+pub fn cooldown_remaining_secs(until: f64, now: f64) -> Option<u32> {
+    (until > now).then(|| ((until - now) / 1000.0).ceil() as u32)
+}
+
+pub fn post_cooldown_remaining() -> Option<u32> {
+    cooldown_remaining_secs(POST_COOLDOWN_UNTIL.with(|c| c.get()), Date::now())
+}
+
+/// Holds back further posts for `ms`, never shortening a longer wait already set.
+pub fn start_post_cooldown(ms: f64) {
+    let until = Date::now() + ms;
+    POST_COOLDOWN_UNTIL.with(|c| c.set(c.get().max(until)));
+}
+
+/// Seconds from a `Retry-After` header. Only the delay form is used by the backend;
+/// anything else falls back to a minute.
+pub fn retry_after_secs(header: Option<String>) -> u32 {
+    header
+        .and_then(|h| h.trim().parse::<u32>().ok())
+        .unwrap_or(DEFAULT_RETRY_AFTER_SECS)
+}
+
+pub async fn report_comment(
+    cx: &AppContext,
+    comment_id: i32,
+    report: &CommentReport,
+) -> Result<(), gloo::net::Error> {
+    let response = AuthClient::new(cx)
+        .post_json(
+            &format!("{}/api/v1/comment/{}/report", BASE_URL, comment_id),
+            report,
+        )
+        .await?;
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.report_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// Hides a comment from everyone but moderators, or shows it again. Moderators only.
+pub async fn set_comment_hidden(
+    cx: &AppContext,
+    comment_id: i32,
+    hidden: bool,
+) -> Result<(), gloo::net::Error> {
+    let response = AuthClient::new(cx)
+        .put_json(
+            &format!("{}/api/v1/moderation/comment/{}", BASE_URL, comment_id),
+            &CommentVisibility { hidden },
+        )
+        .await?;
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.visibility_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// Removes another user's comment. Moderators only.
+pub async fn remove_comment(cx: &AppContext, comment_id: i32) -> Result<(), gloo::net::Error> {
+    let response = AuthClient::new(cx)
+        .delete(&format!("{}/api/v1/moderation/comment/{}", BASE_URL, comment_id))
+        .await?;
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.remove_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cooldown_rounds_up() {
+        assert_eq!(cooldown_remaining_secs(10_000.0, 10_000.0), None);
+        assert_eq!(cooldown_remaining_secs(10_000.0, 12_000.0), None);
+        assert_eq!(cooldown_remaining_secs(10_000.0, 9_999.0), Some(1));
+        assert_eq!(cooldown_remaining_secs(40_000.0, 10_000.0), Some(30));
+    }
+
+    #[test]
+    fn retry_after() {
+        assert_eq!(retry_after_secs(Some(" 12 ".to_string())), 12);
+        assert_eq!(
+            retry_after_secs(Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string())),
+            DEFAULT_RETRY_AFTER_SECS
+        );
+        assert_eq!(retry_after_secs(None), DEFAULT_RETRY_AFTER_SECS);
+    }
+}
@@ -0,0 +1,141 @@
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::js_sys::Date;
+
+use crate::prelude::*;
+
+const OFFLINE_LIST_KEY: &str = "aninfo-offline-list";
+const RECENTLY_VIEWED_KEY: &str = "aninfo-recently-viewed";
+pub const MAX_RECENTLY_VIEWED: usize = 20;
+
+/// Copy of the logged-in user's list, kept so it stays browsable without a
+/// connection, when the session cannot be restored.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OfflineList {
+    pub username: String,
+    pub fav_anime: Vec<UserAnimeResponse>,
+    pub saved_at: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecentlyViewed {
+    pub mal_id: u32,
+    pub title: String,
+    pub image: Option<String>,
+    pub viewed_at: f64,
+}
+
+impl From<&UserAnimeResponse> for AnimeRecObj {
+    fn from(ua: &UserAnimeResponse) -> Self {
+        saved_anime_card(ua.anime_id as u32, &ua.anime_ttl_en, ua.anime_img.clone())
+    }
+}
+
+impl From<&RecentlyViewed> for AnimeRecObj {
+    fn from(rv: &RecentlyViewed) -> Self {
+        saved_anime_card(rv.mal_id, &rv.title, rv.image.clone())
+    }
+}
+
+/// Enough of an entry for `AnimeCardMinimal`, built from what was stored locally.
+fn saved_anime_card(mal_id: u32, title: &str, image: Option<String>) -> AnimeRecObj {
+    let images = Images {
+        image_url: image,
+        small_image_url: None,
+        large_image_url: None,
+    };
+    AnimeRecObj {
+        mal_id,
+        url: format!("https://myanimelist.net/anime/{}", mal_id),
+        title: title.to_string(),
+        images: AnimeImages {
+            jpg: images.clone(),
+            webp: images,
+        },
+    }
+}
+
+pub fn save_offline_list(username: &str, fav_anime: &[UserAnimeResponse]) {
+    let list = OfflineList {
+        username: username.to_string(),
+        fav_anime: fav_anime.to_vec(),
+        saved_at: Date::now(),
+    };
+    if let Err(e) = LocalStorage::set(OFFLINE_LIST_KEY, list) {
+        log!(format!("{:?}", e));
+    }
+}
+
+pub fn load_offline_list() -> Option<OfflineList> {
+    LocalStorage::get::<OfflineList>(OFFLINE_LIST_KEY).ok()
+}
+
+/// Forgets everything tied to the account, on logout or account deletion.
+pub fn clear_offline_data() {
+    LocalStorage::delete(OFFLINE_LIST_KEY);
+}
+
+pub fn load_recently_viewed() -> Vec<RecentlyViewed> {
+    LocalStorage::get::<Vec<RecentlyViewed>>(RECENTLY_VIEWED_KEY).unwrap_or_default()
+}
+
+/// `entry` moved to the front of `list`, without duplicates and at most `max` long.
+pub fn push_recently_viewed(
+    list: Vec<RecentlyViewed>,
+    entry: RecentlyViewed,
+    max: usize,
+) -> Vec<RecentlyViewed> {
+    let mal_id = entry.mal_id;
+    std::iter::once(entry)
+        .chain(list.into_iter().filter(|rv| rv.mal_id != mal_id))
+        .take(max)
+        .collect()
+}
+
+pub fn record_recently_viewed(mal_id: u32, title: String, image: Option<String>) {
+    let entry = RecentlyViewed {
+        mal_id,
+        title,
+        image,
+        viewed_at: Date::now(),
+    };
+    let list = push_recently_viewed(load_recently_viewed(), entry, MAX_RECENTLY_VIEWED);
+    if let Err(e) = LocalStorage::set(RECENTLY_VIEWED_KEY, list) {
+        log!(format!("{:?}", e));
+    }
+}
+
+pub fn is_online() -> bool {
+    web_sys::window().map_or(true, |w| w.navigator().on_line())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rv(mal_id: u32) -> RecentlyViewed {
+        RecentlyViewed {
+            mal_id,
+            title: mal_id.to_string(),
+            image: None,
+            viewed_at: 0.0,
+        }
+    }
+
+    #[test]
+    fn recently_viewed_moves_to_front() {
+        let list = vec![rv(1), rv(2), rv(3)];
+        let ids = push_recently_viewed(list, rv(3), 10)
+            .iter()
+            .map(|r| r.mal_id)
+            .collect::<Vec<u32>>();
+        assert_eq!(ids, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn recently_viewed_is_capped() {
+        let list = (1..=5).map(rv).collect::<Vec<RecentlyViewed>>();
+        let out = push_recently_viewed(list, rv(9), 3);
+        assert_eq!(out.len(), 3);
+        assert_eq!(out[0].mal_id, 9);
+    }
+}
@@ -0,0 +1,89 @@
+use gloo::net::http::Request;
+
+use crate::prelude::*;
+
+pub enum ProfileLookup {
+    Visible(PublicProfile),
+    /// The profile exists but its privacy setting keeps the viewer out.
+    Restricted(RestrictedProfile),
+    NotFound,
+}
+
+fn profile_url(username: &str) -> String {
+    format!(
+        "{}/api/v1/users/{}/profile",
+        BASE_URL,
+        String::from(web_sys::js_sys::encode_uri_component(username))
+    )
+}
+
+/// Loads `username`'s profile as the current viewer sees it. Logged-in viewers are
+/// identified so that friends-only profiles open for friends.
+pub async fn fetch_profile(cx: &AppContext, username: &str) -> Result<ProfileLookup, gloo::net::Error> {
+    let url = profile_url(username);
+    let response = if (*cx).jwt.is_some() {
+        AuthClient::new(cx).get(&url).await?
+    } else {
+        Request::get(&url).send().await?
+    };
+    match response.status() {
+        404 => Ok(ProfileLookup::NotFound),
+        403 => Ok(ProfileLookup::Restricted(response.json::<RestrictedProfile>().await?)),
+        s if (200..300).contains(&s) => Ok(ProfileLookup::Visible(response.json::<PublicProfile>().await?)),
+        s => Err(GlooError(t!("error.profile_failed", status = s))),
+    }
+}
+
+pub async fn set_profile_privacy(cx: &AppContext, privacy: ProfilePrivacy) -> Result<(), gloo::net::Error> {
+    let response = AuthClient::new(cx)
+        .put_json(&format!("{}/api/v1/users/privacy", BASE_URL), &PrivacyPut { privacy })
+        .await?;
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.privacy_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+pub fn is_own_profile(cx: &AppContext, username: &str) -> bool {
+    (*cx).username.as_deref() == Some(username)
+}
+
+/// Letter shown in place of a missing avatar.
+pub fn avatar_initial(username: &str) -> String {
+    username
+        .chars()
+        .find(|c| c.is_alphanumeric())
+        .map(|c| c.to_uppercase().collect())
+        .unwrap_or("?".to_string())
+}
+
+/// Calendar date of an RFC 3339 timestamp, for "joined on".
+pub fn joined_date(joined: &str) -> Option<NaiveDate> {
+    joined
+        .get(..10)
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn avatar_initials() {
+        assert_eq!(avatar_initial("mikasa"), "M");
+        assert_eq!(avatar_initial("_levi"), "L");
+        assert_eq!(avatar_initial("__"), "?");
+    }
+
+    #[test]
+    fn joined_dates() {
+        assert_eq!(
+            joined_date("2023-11-04T09:12:00Z"),
+            NaiveDate::from_ymd_opt(2023, 11, 4)
+        );
+        assert_eq!(joined_date("yesterday"), None);
+    }
+}
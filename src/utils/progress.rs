@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use gloo::storage::{LocalStorage, Storage};
+
+use crate::prelude::*;
+
+const EPISODE_PROGRESS_KEY: &str = "aninfo-episode-progress";
+
+fn load_all_progress() -> HashMap<u32, u32> {
+    LocalStorage::get::<HashMap<u32, u32>>(EPISODE_PROGRESS_KEY).unwrap_or_default()
+}
+
+/// Last episode of `mal_id` marked as watched on this device, `None` if the user
+/// does not track it here.
+pub fn load_episode_progress(mal_id: u32) -> Option<u32> {
+    load_all_progress().get(&mal_id).copied()
+}
+
+pub fn save_episode_progress(mal_id: u32, episode: Option<u32>) {
+    let mut all = load_all_progress();
+    match episode {
+        Some(n) => all.insert(mal_id, n),
+        None => all.remove(&mal_id),
+    };
+    if let Err(e) = LocalStorage::set(EPISODE_PROGRESS_KEY, all) {
+        log!(format!("{:?}", e));
+    }
+}
+
+/// Progress after clicking "watched" on episode `n`: marks everything up to it, or
+/// steps back one episode when `n` was the last one marked.
+pub fn toggle_episode_progress(progress: Option<u32>, n: u32) -> Option<u32> {
+    match progress {
+        Some(p) if p == n => n.checked_sub(1).filter(|p| *p > 0),
+        _ => Some(n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggling_progress() {
+        assert_eq!(toggle_episode_progress(None, 3), Some(3));
+        assert_eq!(toggle_episode_progress(Some(3), 5), Some(5));
+        assert_eq!(toggle_episode_progress(Some(5), 2), Some(2));
+        assert_eq!(toggle_episode_progress(Some(5), 5), Some(4));
+        assert_eq!(toggle_episode_progress(Some(1), 1), None);
+    }
+}
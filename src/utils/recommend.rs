@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// A recommended title merged across every favourite that recommended it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecCandidate {
+    pub entry: AnimeRecObj,
+    pub votes: u32,
+    /// Titles of the favourites that led to this pick, in favourites order.
+    pub because: Vec<String>,
+}
+
+/// Merges the recommendation lists of several favourites. `sources` pairs the
+/// favourite's display title with its recommendations; anything in `exclude`
+/// (usually the favourites themselves) is dropped.
+pub fn aggregate_recommendations(
+    sources: &[(String, Vec<AnimeRecWrapper>)],
+    exclude: &[u32],
+) -> Vec<RecCandidate> {
+    let mut merged: Vec<RecCandidate> = Vec::new();
+
+    for (fav_title, recs) in sources.iter() {
+        for rec in recs.iter() {
+            if exclude.contains(&rec.entry.mal_id) {
+                continue;
+            }
+            match merged.iter_mut().find(|c| c.entry.mal_id == rec.entry.mal_id) {
+                Some(c) => {
+                    c.votes += rec.votes;
+                    if !c.because.contains(fav_title) {
+                        c.because.push(fav_title.clone());
+                    }
+                }
+                None => merged.push(RecCandidate {
+                    entry: rec.entry.clone(),
+                    votes: rec.votes,
+                    because: vec![fav_title.clone()],
+                }),
+            }
+        }
+    }
+
+    merged.sort_by(|a, b| {
+        b.because
+            .len()
+            .cmp(&a.because.len())
+            .then(b.votes.cmp(&a.votes))
+    });
+    merged
+}
+
+/// How many of the favourites carry each genre.
+pub fn genre_counts(favs: &[AnimeObj]) -> HashMap<u32, u32> {
+    let mut counts = HashMap::new();
+    for ao in favs.iter() {
+        for g in ao.genres.iter() {
+            *counts.entry(g.mal_id).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Share of the candidate's genres found among the favourites, weighted by how
+/// many favourites have them. 0 means no overlap, 1 means every genre is in every favourite.
+pub fn genre_overlap(genres: &[MALObj], counts: &HashMap<u32, u32>, n_favs: usize) -> f64 {
+    if genres.is_empty() || n_favs == 0 {
+        return 0.0;
+    }
+    let hits = genres
+        .iter()
+        .map(|g| *counts.get(&g.mal_id).unwrap_or(&0) as f64 / n_favs as f64)
+        .sum::<f64>();
+    hits / genres.len() as f64
+}
+
+/// Final ranking score. Votes are log-scaled so one very popular pair of shows
+/// doesn't drown out titles recommended from several favourites.
+pub fn rec_score(c: &RecCandidate, overlap: f64) -> f64 {
+    (1.0 + c.votes as f64).ln() * (1.0 + overlap) * (1.0 + 0.5 * (c.because.len() as f64 - 1.0))
+}
+
+/// "Because you liked X", "... X and Y", "... X, Y and 2 more".
+pub fn explain_pick(because: &[String]) -> String {
+    match because {
+        [] => "".to_string(),
+        [a] => t!("for_you.because_one", a = a),
+        [a, b] => t!("for_you.because_two", a = a, b = b),
+        [a, b, rest @ ..] => t!("for_you.because_more", a = a, b = b, count = rest.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rec(mal_id: u32, votes: u32) -> AnimeRecWrapper {
+        AnimeRecWrapper {
+            entry: AnimeRecObj {
+                mal_id,
+                url: "".to_string(),
+                title: format!("Anime {}", mal_id),
+                images: AnimeImages::default(),
+            },
+            votes,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_recommendations() {
+        let sources = vec![
+            ("A".to_string(), vec![rec(1, 10), rec(2, 50), rec(9, 100)]),
+            ("B".to_string(), vec![rec(1, 5), rec(3, 1)]),
+        ];
+        let merged = aggregate_recommendations(&sources, &[9]);
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].entry.mal_id, 1);
+        assert_eq!(merged[0].votes, 15);
+        assert_eq!(merged[0].because, vec!["A".to_string(), "B".to_string()]);
+        assert_eq!(merged[1].entry.mal_id, 2);
+        assert!(merged.iter().all(|c| c.entry.mal_id != 9));
+    }
+
+    #[test]
+    fn test_explain_pick() {
+        assert_eq!(explain_pick(&["X".to_string()]), "Because you liked X");
+        assert_eq!(
+            explain_pick(&["X".to_string(), "Y".to_string()]),
+            "Because you liked X and Y"
+        );
+        assert_eq!(
+            explain_pick(&["X".to_string(), "Y".to_string(), "Z".to_string(), "W".to_string()]),
+            "Because you liked X, Y and 2 more"
+        );
+    }
+}
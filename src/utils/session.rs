@@ -0,0 +1,273 @@
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use gloo::net::http::{Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use web_sys::{js_sys::Date, RequestCredentials};
+
+use crate::prelude::*;
+
+/// Refresh the access token this many seconds before it expires.
+const REFRESH_MARGIN_SECS: f64 = 60.0;
+/// Refresh interval for tokens whose expiry cannot be read, e.g. opaque ones.
+const FALLBACK_REFRESH_MS: u32 = 5 * 60 * 1000;
+/// Shortest wait before a scheduled refresh, so a token that is already due (or
+/// a server handing out short-lived ones) cannot make the refreshes loop.
+const MIN_REFRESH_MS: u32 = 10 * 1000;
+
+type RefreshFuture = Shared<LocalBoxFuture<'static, Result<String, String>>>;
+
+thread_local! {
+    /// The refresh currently on its way, if any. The backend rotates the refresh
+    /// cookie on every use, so parallel refreshes would invalidate each other.
+    static REFRESH_IN_FLIGHT: RefCell<Option<RefreshFuture>> = RefCell::new(None);
+}
+
+/// Body of `/api/v1/users/refresh`. The refresh token itself never reaches JS: the
+/// backend keeps it in an HttpOnly cookie scoped to the API.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionTokens {
+    pub access_token: String,
+}
+
+#[derive(Deserialize)]
+struct AccessClaims {
+    exp: f64,
+}
+
+/// Reads the `exp` claim (seconds since epoch) out of an access token. Only used to
+/// schedule refreshes; verifying the token is the server's job.
+pub fn access_token_expiry(token: &str) -> Option<f64> {
+    let payload = token.split('.').nth(1)?;
+    let mut b64 = payload.replace('-', "+").replace('_', "/");
+    while b64.len() % 4 != 0 {
+        b64.push('=');
+    }
+    let json = web_sys::window()?.atob(&b64).ok()?;
+    serde_json::from_str::<AccessClaims>(&json)
+        .ok()
+        .map(|c| c.exp)
+}
+
+/// Milliseconds until the token should be refreshed.
+pub fn refresh_delay_ms(token: &str) -> u32 {
+    scheduled_delay_ms(access_token_expiry(token), Date::now())
+}
+
+/// Delay before refreshing a token expiring at `exp` (seconds since epoch), given
+/// the current time in milliseconds. Never shorter than `MIN_REFRESH_MS`.
+fn scheduled_delay_ms(exp: Option<f64>, now_ms: f64) -> u32 {
+    match exp {
+        Some(exp) => ((exp - REFRESH_MARGIN_SECS) * 1000.0 - now_ms)
+            .clamp(MIN_REFRESH_MS as f64, u32::MAX as f64) as u32,
+        None => FALLBACK_REFRESH_MS,
+    }
+}
+
+fn needs_refresh(token: &str) -> bool {
+    match access_token_expiry(token) {
+        Some(exp) => exp - REFRESH_MARGIN_SECS <= Date::now() / 1000.0,
+        None => false,
+    }
+}
+
+/// Exchanges the refresh cookie for a new access token. Callers arriving while a
+/// refresh is already running wait for that one instead of starting their own.
+pub async fn refresh_session() -> Result<String, gloo::net::Error> {
+    let refresh = REFRESH_IN_FLIGHT.with(|in_flight| {
+        in_flight
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                async {
+                    let result = request_refresh().await.map_err(|e| e.to_string());
+                    REFRESH_IN_FLIGHT.with(|in_flight| in_flight.borrow_mut().take());
+                    result
+                }
+                .boxed_local()
+                .shared()
+            })
+            .clone()
+    });
+    refresh.await.map_err(GlooError)
+}
+
+async fn request_refresh() -> Result<String, gloo::net::Error> {
+    let response = Request::post(&format!("{}/api/v1/users/refresh", BASE_URL))
+        .credentials(RequestCredentials::Include)
+        .send()
+        .await?;
+
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.refresh_failed",
+            status = response.status()
+        )));
+    }
+    Ok(response.json::<SessionTokens>().await?.access_token)
+}
+
+/// `cx` with the logged-in user's data filled in.
+pub fn session_ctx(cx: &AppCtx, access_token: String, user: UserForResponse) -> AppCtx {
+    let mut fav_ids = user
+        .fav_anime
+        .iter()
+        .map(|ua_response| ua_response.anime_id)
+        .collect::<Vec<i32>>();
+    fav_ids.sort();
+
+    cx.update_jwt_into(Some(access_token))
+        .update_username_into(Some(user.username))
+        .update_uuid_into(Some(user.uuid))
+        .update_role_into(user.role)
+        .update_fav_anime_into(Some(user.fav_anime))
+        .update_fav_anime_id_into(Some(fav_ids))
+}
+
+/// Drops every piece of user data kept in memory.
+pub fn clear_session(cx: &AppContext) {
+    cx.dispatch(AppAction::ClearSession);
+}
+
+/// Asks the backend to revoke the refresh cookie, then clears the session locally
+/// whether or not that request went through. Unlike a failed refresh, this also
+/// drops the copy of the list kept for offline use.
+pub async fn end_session(cx: &AppContext) {
+    let mut req = Request::post(&format!("{}/api/v1/users/logout", BASE_URL))
+        .credentials(RequestCredentials::Include);
+    if let Some(jwt) = (*cx).jwt.as_ref() {
+        req = req.header("Authorization", &format!("Bearer {}", jwt));
+    }
+    if let Err(e) = req.send().await {
+        log!(format!("{:?}", e));
+    }
+    clear_offline_data();
+    clear_session(cx);
+}
+
+/// The one place requests to the ANiNFO backend should go through. Attaches the
+/// access token, refreshes it when it is about to expire, and logs the user out
+/// cleanly on any 401.
+#[derive(Clone)]
+pub struct AuthClient {
+    cx: AppContext,
+    jwt: Option<String>,
+}
+
+impl AuthClient {
+    pub fn new(cx: &AppContext) -> Self {
+        AuthClient {
+            cx: cx.clone(),
+            jwt: (*cx).jwt.clone(),
+        }
+    }
+
+    /// A client for a token that has not reached `cx` yet, e.g. while a session is
+    /// being restored.
+    pub fn with_token(cx: &AppContext, jwt: String) -> Self {
+        AuthClient {
+            cx: cx.clone(),
+            jwt: Some(jwt),
+        }
+    }
+
+    async fn access_token(&self) -> Option<String> {
+        let jwt = self.jwt.clone()?;
+        if !needs_refresh(&jwt) {
+            return Some(jwt);
+        }
+
+        match refresh_session().await {
+            Ok(new_jwt) => {
+                self.cx.dispatch(AppAction::SetJwt(new_jwt.clone()));
+                Some(new_jwt)
+            }
+            Err(e) => {
+                log!(format!("{:?}", e));
+                clear_session(&self.cx);
+                None
+            }
+        }
+    }
+
+    async fn authorize(&self, req: RequestBuilder) -> RequestBuilder {
+        let req = req
+            .header("Content-Type", "application/json")
+            .credentials(RequestCredentials::Include);
+        match self.access_token().await {
+            Some(jwt) => req.header("Authorization", &format!("Bearer {}", jwt)),
+            None => req,
+        }
+    }
+
+    async fn finish(&self, response: Response) -> Result<Response, gloo::net::Error> {
+        if response.status() == 401 {
+            end_session(&self.cx).await;
+            return Err(GlooError(t!("error.session_expired")));
+        }
+        Ok(response)
+    }
+
+    pub async fn get(&self, url: &str) -> Result<Response, gloo::net::Error> {
+        let response = self.authorize(Request::get(url)).await.send().await?;
+        self.finish(response).await
+    }
+
+    pub async fn post(&self, url: &str) -> Result<Response, gloo::net::Error> {
+        let response = self.authorize(Request::post(url)).await.send().await?;
+        self.finish(response).await
+    }
+
+    pub async fn post_json<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<Response, gloo::net::Error> {
+        let response = self
+            .authorize(Request::post(url))
+            .await
+            .json(body)?
+            .send()
+            .await?;
+        self.finish(response).await
+    }
+
+    pub async fn put_json<T: Serialize>(
+        &self,
+        url: &str,
+        body: &T,
+    ) -> Result<Response, gloo::net::Error> {
+        let response = self
+            .authorize(Request::put(url))
+            .await
+            .json(body)?
+            .send()
+            .await?;
+        self.finish(response).await
+    }
+
+    pub async fn delete(&self, url: &str) -> Result<Response, gloo::net::Error> {
+        let response = self.authorize(Request::delete(url)).await.send().await?;
+        self.finish(response).await
+    }
+
+    /// `get` followed by decoding the body, for endpoints that only return data.
+    pub async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, gloo::net::Error> {
+        self.get(url).await?.json::<T>().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refresh_delays() {
+        let now_ms = 1_000_000.0;
+        // Due in ten minutes, refreshed a minute early
+        assert_eq!(scheduled_delay_ms(Some(1_000.0 + 600.0), now_ms), 540_000);
+        // Already due, or expired
+        assert_eq!(scheduled_delay_ms(Some(1_000.0 + 30.0), now_ms), MIN_REFRESH_MS);
+        assert_eq!(scheduled_delay_ms(Some(0.0), now_ms), MIN_REFRESH_MS);
+        // No readable expiry
+        assert_eq!(scheduled_delay_ms(None, now_ms), FALLBACK_REFRESH_MS);
+    }
+}
@@ -0,0 +1,226 @@
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::{js_sys::Date, MediaQueryList};
+
+use crate::prelude::*;
+
+const SETTINGS_KEY: &str = "aninfo-settings";
+/// Browser UI colours of the installed app, matching `--base-bg-dark` and the light background.
+const THEME_COLOR_DARK: &str = "#00070f";
+const THEME_COLOR_LIGHT: &str = "#ffffff";
+
+/// Preferences that outlive a page load. Guests keep them in localStorage only;
+/// logged-in users also keep them on the backend so they follow the account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct UserSettings {
+    pub theme: Theme,
+    pub language: Language,
+    #[serde(default = "default_title_order")]
+    pub title_order: Vec<TitleKind>,
+    pub nsfw: bool,
+    #[serde(default)]
+    pub scroll_mode: ScrollMode,
+    /// Milliseconds since epoch of the last change the user made, 0 if they never
+    /// changed anything. Decides which side wins when guest and account settings meet.
+    #[serde(default)]
+    pub updated_at: f64,
+}
+
+impl Default for UserSettings {
+    fn default() -> Self {
+        UserSettings {
+            theme: AUTO_THEME,
+            language: Language::EN,
+            title_order: default_title_order(),
+            nsfw: false,
+            scroll_mode: ScrollMode::Paged,
+            updated_at: 0.0,
+        }
+    }
+}
+
+impl UserSettings {
+    pub fn from_ctx(cx: &AppCtx) -> Self {
+        UserSettings {
+            theme: cx.theme,
+            language: cx.language,
+            title_order: cx.title_order.clone(),
+            nsfw: cx.nsfw,
+            scroll_mode: cx.scroll_mode,
+            updated_at: 0.0,
+        }
+    }
+
+    /// Same preferences, regardless of when they were set.
+    pub fn same_prefs(&self, other: &UserSettings) -> bool {
+        self.theme == other.theme
+            && self.language == other.language
+            && self.title_order == other.title_order
+            && self.nsfw == other.nsfw
+            && self.scroll_mode == other.scroll_mode
+    }
+
+    pub fn touched(&self, now: f64) -> Self {
+        UserSettings {
+            updated_at: now,
+            ..self.clone()
+        }
+    }
+}
+
+/// Settings to use once a guest logs in. The side changed most recently wins, so
+/// picking a theme right before logging in sticks, while a fresh browser that never
+/// changed anything simply takes on the account's settings.
+pub fn merge_settings(guest: Option<UserSettings>, account: Option<UserSettings>) -> UserSettings {
+    match (guest, account) {
+        (Some(g), Some(a)) if g.updated_at > a.updated_at => g,
+        (_, Some(a)) => a,
+        (Some(g), None) => g,
+        (None, None) => UserSettings::default(),
+    }
+}
+
+pub fn load_local_settings() -> Option<UserSettings> {
+    LocalStorage::get::<UserSettings>(SETTINGS_KEY).ok()
+}
+
+pub fn save_local_settings(settings: &UserSettings) {
+    if let Err(e) = LocalStorage::set(SETTINGS_KEY, settings) {
+        log!(format!("{:?}", e));
+    }
+}
+
+pub fn prefers_dark_query() -> Option<MediaQueryList> {
+    web_sys::window()?
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()
+        .flatten()
+}
+
+pub fn system_prefers_dark() -> bool {
+    prefers_dark_query().map_or(false, |mql| mql.matches())
+}
+
+/// Reflects the settings on `<html>` so that page-level styles and `lang` match, and
+/// on the `theme-color` meta tag the installed app's title bar follows.
+pub fn apply_document_settings(settings: &UserSettings, prefers_dark: bool) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let Some(html_element) = document.document_element() else {
+        return;
+    };
+
+    let (class, color) = match settings.theme.resolve(prefers_dark) {
+        Theme::Light => ("light", THEME_COLOR_LIGHT),
+        _ => ("dark", THEME_COLOR_DARK),
+    };
+    if let Ok(Some(meta)) = document.query_selector("meta[name=\"theme-color\"]") {
+        let _ = meta.set_attribute("content", color);
+    }
+    let _ = html_element.set_attribute("class", class);
+    let _ = html_element.set_attribute("lang", settings.language.locale());
+}
+
+/// The account's saved settings, `None` if it has never saved any.
+pub async fn fetch_remote_settings(
+    client: &AuthClient,
+) -> Result<Option<UserSettings>, gloo::net::Error> {
+    let response = client
+        .get(&format!("{}/api/v1/users/settings", BASE_URL))
+        .await?;
+    match response.status() {
+        404 => Ok(None),
+        s if (200..300).contains(&s) => Ok(Some(response.json::<UserSettings>().await?)),
+        s => Err(GlooError(t!("error.settings_load_failed", status = s))),
+    }
+}
+
+pub async fn push_remote_settings(
+    client: &AuthClient,
+    settings: &UserSettings,
+) -> Result<(), gloo::net::Error> {
+    let response = client
+        .put_json(&format!("{}/api/v1/users/settings", BASE_URL), settings)
+        .await?;
+    if !response.ok() {
+        return Err(GlooError(t!(
+            "error.settings_save_failed",
+            status = response.status()
+        )));
+    }
+    Ok(())
+}
+
+/// Called once per login: merges what this browser had with what the account has,
+/// applies the result and writes it back wherever it is out of date.
+pub async fn sync_settings_on_login(cx: &AppContext) {
+    let client = AuthClient::new(cx);
+    let account = match fetch_remote_settings(&client).await {
+        Ok(account) => account,
+        Err(e) => {
+            log!(format!("{:?}", e));
+            return;
+        }
+    };
+
+    let merged = merge_settings(load_local_settings(), account.clone());
+    save_local_settings(&merged);
+    if account.as_ref() != Some(&merged) {
+        if let Err(e) = push_remote_settings(&client, &merged).await {
+            log!(format!("{:?}", e));
+        }
+    }
+    cx.dispatch((*cx).update_settings_into(&merged).into());
+}
+
+/// Writes a changed setting to localStorage and, when logged in, to the backend.
+/// Does nothing if `settings` is what was stored already, e.g. right after loading.
+pub fn persist_settings(cx: &AppContext, settings: &UserSettings) {
+    let settings = match load_local_settings() {
+        Some(stored) if stored.same_prefs(settings) => return,
+        Some(_) => settings.touched(Date::now()),
+        // Nothing chosen yet, only the defaults being written down for the first time
+        None => settings.clone(),
+    };
+    save_local_settings(&settings);
+
+    if (*cx).jwt.is_some() {
+        let client = AuthClient::new(cx);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = push_remote_settings(&client, &settings).await {
+                log!(format!("{:?}", e));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with(theme: Theme, updated_at: f64) -> UserSettings {
+        UserSettings {
+            theme,
+            updated_at,
+            ..UserSettings::default()
+        }
+    }
+
+    #[test]
+    fn newer_side_wins_on_login() {
+        let guest = with(LIGHT_THEME, 20.0);
+        let account = with(DARK_THEME, 10.0);
+        assert_eq!(merge_settings(Some(guest.clone()), Some(account.clone())), guest);
+
+        let guest = with(LIGHT_THEME, 5.0);
+        assert_eq!(merge_settings(Some(guest), Some(account.clone())), account);
+    }
+
+    #[test]
+    fn untouched_guest_takes_account_settings() {
+        let account = with(LIGHT_THEME, 0.0);
+        let guest = UserSettings::default();
+        assert_eq!(merge_settings(Some(guest), Some(account.clone())), account);
+        assert_eq!(merge_settings(None, None), UserSettings::default());
+    }
+}
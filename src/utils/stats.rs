@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+/// Aggregates shown on `/stats`. Distributions are sorted with the largest count first,
+/// except `timeline` which is chronological.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct ListStats {
+    pub total_anime: usize,
+    /// Every episode of every entry, watched or not.
+    pub total_episodes: u32,
+    pub total_minutes: u32,
+    pub genres: Vec<(String, u32)>,
+    pub studios: Vec<(String, u32)>,
+    /// MyAnimeList's public score of each entry, not the user's own rating. Index
+    /// `n` counts scores in `[n + 1, n + 2)`, so 10 buckets for 1 to 10.
+    pub scores: Vec<(String, u32)>,
+    pub timeline: Vec<(String, u32)>,
+}
+
+/// Reads Jikan's duration strings such as "24 min per ep", "1 hr 35 min" or "45 sec".
+pub fn parse_duration_minutes(s: &str) -> Option<u32> {
+    let tokens = s.split_whitespace().collect::<Vec<&str>>();
+    let mut minutes = 0;
+    let mut found = false;
+
+    for w in tokens.windows(2) {
+        if let Ok(n) = w[0].parse::<u32>() {
+            match w[1] {
+                "hr" | "hrs" | "hour" | "hours" => minutes += n * 60,
+                "min" | "mins" | "minute" | "minutes" => minutes += n,
+                // Shorts still count as a minute each
+                "sec" | "secs" | "second" | "seconds" => minutes += 1,
+                _ => continue,
+            }
+            found = true;
+        }
+    }
+
+    if found {
+        Some(minutes)
+    } else {
+        None
+    }
+}
+
+fn season_index(season: &str) -> u8 {
+    match season.to_lowercase().as_str() {
+        "winter" => 0,
+        "spring" => 1,
+        "summer" => 2,
+        "fall" => 3,
+        _ => 4,
+    }
+}
+
+fn sorted_counts(counts: HashMap<String, u32>) -> Vec<(String, u32)> {
+    let mut v = counts.into_iter().collect::<Vec<(String, u32)>>();
+    v.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    v
+}
+
+pub fn compute_stats(list: &[AnimeObj]) -> ListStats {
+    let mut genres = HashMap::new();
+    let mut studios = HashMap::new();
+    let mut scores = vec![0u32; 10];
+    let mut timeline: HashMap<(u32, u8), (String, u32)> = HashMap::new();
+    let mut total_episodes = 0;
+    let mut total_minutes = 0;
+
+    for ao in list.iter() {
+        let eps = ao.episodes.unwrap_or(0) as u32;
+        total_episodes += eps;
+        total_minutes += eps
+            * ao
+                .duration
+                .as_deref()
+                .and_then(parse_duration_minutes)
+                .unwrap_or(0);
+
+        for g in ao.genres.iter() {
+            *genres.entry(g.name.clone()).or_insert(0) += 1;
+        }
+        for s in ao.studios.iter() {
+            *studios.entry(s.name.clone()).or_insert(0) += 1;
+        }
+
+        if let Some(score) = ao.score.as_ref().and_then(|s| s.parse::<f64>().ok()) {
+            if score >= 1.0 {
+                scores[((score.floor() as usize) - 1).min(9)] += 1;
+            }
+        }
+
+        // Older entries have no season, so fall back to the air date's year
+        let year = ao.year.or_else(|| {
+            ao.aired
+                .from
+                .as_ref()
+                .and_then(|d| d.get(0..4))
+                .and_then(|y| y.parse::<u32>().ok())
+        });
+        if let Some(y) = year {
+            let (label, idx) = match ao.season.as_ref().map(|s| (s, season_index(s))) {
+                Some((s, idx)) if idx < 4 => (
+                    t!(
+                        "stats.season_label",
+                        season = t!(&format!("season.{}", s.to_lowercase())),
+                        year = y
+                    ),
+                    idx,
+                ),
+                _ => (y.to_string(), 4),
+            };
+            timeline.entry((y, idx)).or_insert((label, 0)).1 += 1;
+        }
+    }
+
+    let mut timeline = timeline.into_iter().collect::<Vec<((u32, u8), (String, u32))>>();
+    timeline.sort_by(|a, b| a.0.cmp(&b.0));
+
+    ListStats {
+        total_anime: list.len(),
+        total_episodes,
+        total_minutes,
+        genres: sorted_counts(genres),
+        studios: sorted_counts(studios),
+        scores: scores
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| ((i + 1).to_string(), n))
+            .collect(),
+        timeline: timeline.into_iter().map(|(_, v)| v).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_minutes() {
+        assert_eq!(parse_duration_minutes("24 min per ep"), Some(24));
+        assert_eq!(parse_duration_minutes("1 hr 35 min"), Some(95));
+        assert_eq!(parse_duration_minutes("2 hr"), Some(120));
+        assert_eq!(parse_duration_minutes("45 sec per ep"), Some(1));
+        assert_eq!(parse_duration_minutes("Unknown"), None);
+    }
+
+    fn anime(
+        score: &str,
+        episodes: u16,
+        season: Option<&str>,
+        year: u32,
+        genres: &[&str],
+    ) -> AnimeObj {
+        let genres = genres
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                serde_json::json!({"mal_id": i, "type": "anime", "name": name, "url": ""})
+            })
+            .collect::<Vec<serde_json::Value>>();
+        serde_json::from_value(serde_json::json!({
+            "mal_id": 1,
+            "url": "",
+            "images": {"jpg": {}, "webp": {}},
+            "approved": true,
+            "titles": [],
+            "title_english": null,
+            "title_japanese": null,
+            "source": null,
+            "episodes": episodes,
+            "duration": "24 min per ep",
+            "status": null,
+            "airing": false,
+            "score": score,
+            "scored_by": null,
+            "rank": null,
+            "popularity": null,
+            "year": year,
+            "type": "TV",
+            "synopsis": null,
+            "background": null,
+            "studios": [],
+            "genres": genres,
+            "season": season,
+            "themes": [],
+            "aired": {"from": format!("{}-04-01T00:00:00+00:00", year)},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_compute_stats() {
+        let list = [
+            anime("8.9", 12, Some("spring"), 2023, &["Action", "Drama"]),
+            anime("7.2", 24, Some("fall"), 2022, &["Action"]),
+            anime("8.1", 1, None, 2021, &["Drama", "Romance"]),
+            anime("8.4", 12, Some("spring"), 2023, &["Action"]),
+        ];
+        let stats = compute_stats(&list);
+
+        assert_eq!(stats.total_anime, 4);
+        assert_eq!(stats.total_episodes, 49);
+        assert_eq!(stats.total_minutes, 49 * 24);
+        assert_eq!(
+            stats.genres,
+            vec![
+                ("Action".to_string(), 3),
+                ("Drama".to_string(), 2),
+                ("Romance".to_string(), 1),
+            ]
+        );
+        // Buckets 7 and 8 of the MAL scores
+        assert_eq!(stats.scores[6], ("7".to_string(), 1));
+        assert_eq!(stats.scores[7], ("8".to_string(), 3));
+        assert_eq!(stats.scores.iter().map(|(_, n)| n).sum::<u32>(), 4);
+        assert_eq!(
+            stats.timeline,
+            vec![
+                ("2021".to_string(), 1),
+                ("Fall 2022".to_string(), 1),
+                ("Spring 2023".to_string(), 2),
+            ]
+        );
+        let empty = compute_stats(&[]);
+        assert_eq!(empty.total_anime, 0);
+        assert!(empty.genres.is_empty() && empty.timeline.is_empty());
+    }
+}
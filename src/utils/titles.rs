@@ -0,0 +1,171 @@
+use crate::prelude::*;
+
+/// Which of an anime's titles to show. MAL lists them in `titles` tagged with a
+/// type, where "Default" is the romanized Japanese title.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TitleKind {
+    Romaji,
+    English,
+    Native,
+    Synonym,
+}
+
+impl TitleKind {
+    pub const ALL: [TitleKind; 4] = [
+        TitleKind::Romaji,
+        TitleKind::English,
+        TitleKind::Native,
+        TitleKind::Synonym,
+    ];
+
+    fn mal_type(&self) -> &'static str {
+        match self {
+            TitleKind::Romaji => "Default",
+            TitleKind::English => "English",
+            TitleKind::Native => "Japanese",
+            TitleKind::Synonym => "Synonym",
+        }
+    }
+
+    /// Message catalog key of the name shown in the settings.
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            TitleKind::Romaji => "titles.romaji",
+            TitleKind::English => "titles.english",
+            TitleKind::Native => "titles.native",
+            TitleKind::Synonym => "titles.synonym",
+        }
+    }
+}
+
+/// English first, as titles were shown before the preference existed.
+pub fn default_title_order() -> Vec<TitleKind> {
+    vec![
+        TitleKind::English,
+        TitleKind::Romaji,
+        TitleKind::Native,
+        TitleKind::Synonym,
+    ]
+}
+
+/// The first title in `order` the anime has. Falls back to MAL's first listed title
+/// so there is always something to show.
+pub fn pick_title(titles: &[Title], order: &[TitleKind]) -> String {
+    order
+        .iter()
+        .find_map(|kind| {
+            titles
+                .iter()
+                .find(|t| t.r#type == kind.mal_type() && !t.title.trim().is_empty())
+        })
+        .or(titles.first())
+        .map(|t| t.title.clone())
+        .unwrap_or_default()
+}
+
+/// Same for episodes, which carry separate fields instead of a list and have no
+/// synonyms.
+pub fn pick_episode_title(eo: &AnimeEpisode, order: &[TitleKind]) -> String {
+    order
+        .iter()
+        .find_map(|kind| {
+            let title = match kind {
+                TitleKind::Romaji => eo.title_romanji.clone(),
+                TitleKind::English => Some(eo.title.clone()),
+                TitleKind::Native => eo.title_japanese.clone(),
+                TitleKind::Synonym => None,
+            };
+            // A blank field moves on to the next kind, like `pick_title` does
+            title.filter(|t| !t.trim().is_empty())
+        })
+        .unwrap_or(eo.title.clone())
+}
+
+pub fn display_title(cx: &AppContext, ao: &AnimeObj) -> String {
+    pick_title(&ao.titles, &(*cx).title_order)
+}
+
+pub fn display_episode_title(cx: &AppContext, eo: &AnimeEpisode) -> String {
+    pick_episode_title(eo, &(*cx).title_order)
+}
+
+/// `order` with `kind` swapped one place towards the front.
+pub fn move_title_kind_up(order: &[TitleKind], kind: TitleKind) -> Vec<TitleKind> {
+    let mut order = order.to_vec();
+    if let Some(i) = order.iter().position(|k| *k == kind) {
+        if i > 0 {
+            order.swap(i - 1, i);
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles() -> Vec<Title> {
+        [
+            ("Default", "Shingeki no Kyojin"),
+            ("Synonym", "AoT"),
+            ("Japanese", "進撃の巨人"),
+            ("English", "Attack on Titan"),
+        ]
+        .iter()
+        .map(|(t, s)| Title {
+            r#type: t.to_string(),
+            title: s.to_string(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn follows_the_order() {
+        let order = [TitleKind::Native, TitleKind::English];
+        assert_eq!(pick_title(&titles(), &order), "進撃の巨人");
+        assert_eq!(
+            pick_title(&titles(), &default_title_order()),
+            "Attack on Titan"
+        );
+    }
+
+    #[test]
+    fn falls_back_along_the_chain() {
+        let without_english = titles()
+            .into_iter()
+            .filter(|t| t.r#type != "English")
+            .collect::<Vec<Title>>();
+        assert_eq!(
+            pick_title(&without_english, &default_title_order()),
+            "Shingeki no Kyojin"
+        );
+        assert_eq!(pick_title(&without_english, &[]), "Shingeki no Kyojin");
+        assert_eq!(pick_title(&[], &default_title_order()), "");
+    }
+
+    #[test]
+    fn blank_episode_titles_are_skipped() {
+        let eo = AnimeEpisode {
+            mal_id: 1,
+            url: None,
+            title: "To You, in 2000 Years".to_string(),
+            title_japanese: Some("二千年後の君へ".to_string()),
+            title_romanji: Some(" ".to_string()),
+            aired: None,
+            forum_url: None,
+            score: None,
+            filler: false,
+            recap: false,
+        };
+        let order = [TitleKind::Romaji, TitleKind::Native, TitleKind::English];
+        assert_eq!(pick_episode_title(&eo, &order), "二千年後の君へ");
+        assert_eq!(pick_episode_title(&eo, &[TitleKind::Romaji]), "To You, in 2000 Years");
+    }
+
+    #[test]
+    fn moving_up() {
+        let order = move_title_kind_up(&default_title_order(), TitleKind::Native);
+        assert_eq!(order[1], TitleKind::Native);
+        assert_eq!(move_title_kind_up(&order, TitleKind::English), order);
+    }
+}
@@ -0,0 +1,196 @@
+// ANiNFO service worker: keeps the app shell and visited API responses available offline.
+//
+// Trunk fingerprints the wasm/js/css bundles, so the shell is discovered from
+// index.html at install time instead of being listed here. Bump CACHE_VERSION to
+// drop old caches when the caching strategy changes.
+const CACHE_VERSION = "v3";
+const SHELL_CACHE = `aninfo-shell-${CACHE_VERSION}`;
+const API_CACHE = `aninfo-api-${CACHE_VERSION}`;
+
+// Visited API responses are kept for offline use, but only the most recent ones
+// and not forever
+const API_CACHE_MAX_ENTRIES = 300;
+const API_CACHE_MAX_AGE_MS = 7 * 24 * 60 * 60 * 1000;
+// Set on cached API responses, since Date is not readable on cross-origin ones
+const CACHED_AT_HEADER = "x-aninfo-cached-at";
+
+const API_ORIGINS = [
+    "https://api.jikan.moe",
+    "https://aninfo-server.shuttleapp.rs",
+];
+
+const STATIC_ASSETS = [
+    "./",
+    "./index.html",
+    "./manifest.json",
+    "./static/icons/icon-192.png",
+    "./static/icons/icon-512.png",
+    "./static/favicon.png",
+    "./static/auto.svg",
+    "./static/light.svg",
+    "./static/moon.svg",
+    "./static/search.svg",
+    "./static/menu.svg",
+    "./static/close_nb.png",
+    "./static/top.svg",
+    "./static/heart.svg",
+    "./static/heart_filled.svg",
+    "./static/bookmark.svg",
+    "./static/comment.svg",
+    "./static/plus.svg",
+    "./static/episode.png",
+    "./static/character.png",
+    "./static/music.png",
+    "./static/download.png",
+    "./static/dropdown.png",
+    "./static/dialog_close.png",
+];
+
+async function shellAssets() {
+    const response = await fetch("./index.html", { cache: "no-cache" });
+    const html = await response.text();
+    const bundles = [...html.matchAll(/["']([^"']+\.(?:wasm|js|css))["']/g)]
+        .map((m) => m[1])
+        .filter((url) => !/^https?:/.test(url));
+    return [...new Set([...STATIC_ASSETS, ...bundles])];
+}
+
+self.addEventListener("install", (event) => {
+    event.waitUntil(
+        (async () => {
+            const cache = await caches.open(SHELL_CACHE);
+            // One missing icon should not keep the worker from installing
+            const assets = await shellAssets();
+            await Promise.all(assets.map((url) => cache.add(url).catch(() => {})));
+            await self.skipWaiting();
+        })()
+    );
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil(
+        (async () => {
+            const keys = await caches.keys();
+            await Promise.all(
+                keys
+                    .filter((key) => key.startsWith("aninfo-") && ![SHELL_CACHE, API_CACHE].includes(key))
+                    .map((key) => caches.delete(key))
+            );
+            await self.clients.claim();
+        })()
+    );
+});
+
+async function stamped(response) {
+    const headers = new Headers(response.headers);
+    headers.set(CACHED_AT_HEADER, String(Date.now()));
+    return new Response(await response.blob(), {
+        status: response.status,
+        statusText: response.statusText,
+        headers,
+    });
+}
+
+function isExpired(response) {
+    const cachedAt = Number(response.headers.get(CACHED_AT_HEADER));
+    return !cachedAt || Date.now() - cachedAt > API_CACHE_MAX_AGE_MS;
+}
+
+// Keys come back in insertion order, and `put` re-inserts, so the oldest go first
+async function trimApiCache(cache) {
+    const keys = await cache.keys();
+    const excess = keys.length - API_CACHE_MAX_ENTRIES;
+    if (excess > 0) {
+        await Promise.all(keys.slice(0, excess).map((key) => cache.delete(key)));
+    }
+}
+
+// Network first, so data is fresh whenever there is a connection
+async function networkFirst(request) {
+    const cache = await caches.open(API_CACHE);
+    try {
+        const response = await fetch(request);
+        if (response.ok) {
+            cache
+                .put(request, await stamped(response.clone()))
+                .then(() => trimApiCache(cache));
+        }
+        return response;
+    } catch (e) {
+        const cached = await cache.match(request);
+        if (cached && !isExpired(cached)) {
+            return cached;
+        }
+        if (cached) {
+            cache.delete(request);
+        }
+        throw e;
+    }
+}
+
+// Fingerprinted bundles never change under the same URL
+async function cacheFirst(request) {
+    const cached = await caches.match(request);
+    if (cached) {
+        return cached;
+    }
+    const response = await fetch(request);
+    if (response.ok) {
+        const cache = await caches.open(SHELL_CACHE);
+        cache.put(request, response.clone());
+    }
+    return response;
+}
+
+// Icons, images and the manifest keep their URL across releases, so the cached copy
+// is served right away and replaced in the background for the next visit
+async function staleWhileRevalidate(event) {
+    const request = event.request;
+    const cache = await caches.open(SHELL_CACHE);
+    const cached = await cache.match(request);
+    const refresh = fetch(request).then((response) => {
+        if (response.ok) {
+            return cache.put(request, response.clone()).then(() => response);
+        }
+        return response;
+    });
+    if (cached) {
+        event.waitUntil(refresh.catch(() => {}));
+        return cached;
+    }
+    return refresh;
+}
+
+function isUnversioned(url) {
+    const path = url.pathname;
+    return path.includes("/static/") || path.endsWith("/manifest.json");
+}
+
+self.addEventListener("fetch", (event) => {
+    const request = event.request;
+    if (request.method !== "GET") {
+        return;
+    }
+    const url = new URL(request.url);
+
+    // Every route is served by the same index.html
+    if (request.mode === "navigate") {
+        event.respondWith(
+            fetch(request).catch(async () => (await caches.match("./index.html")) || Response.error())
+        );
+        return;
+    }
+
+    if (API_ORIGINS.includes(url.origin)) {
+        // Responses for a logged-in user are theirs only and must not outlive logout
+        if (request.headers.has("Authorization")) {
+            return;
+        }
+        event.respondWith(networkFirst(request));
+        return;
+    }
+
+    if (url.origin === self.location.origin) {
+        event.respondWith(isUnversioned(url) ? staleWhileRevalidate(event) : cacheFirst(request));
+    }
+});